@@ -1,43 +1,34 @@
-use std::error::Error;
-use std::fs;
+use aoc_core::{Error, Result};
 
-const FILE_PATH: &str = "./day_1_input.txt";
-
-pub fn run() -> Result<i32, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-
-    let sum = calculate_sim_score(&contents);
-
-    Ok(sum)
-}
-
-fn calculate_sim_score(contents: &str) -> i32 {
+pub fn parse(contents: &str) -> Result<(Vec<i32>, Vec<i32>)> {
     let mut left_list: Vec<i32> = Vec::new();
     let mut right_list: Vec<i32> = Vec::new();
 
     for line in contents.lines() {
-        let words: Vec<&str> = line.split_whitespace().collect();
-        let numbers = words
-            .iter()
-            .map(|x| x.parse::<i32>().unwrap())
-            .collect::<Vec<i32>>();
-
-        left_list.push(numbers[0]);
-        right_list.push(numbers[1]);
+        let numbers = line
+            .split_whitespace()
+            .map(|x| {
+                x.parse::<i32>()
+                    .map_err(|e| Error::parse_at(contents, x, e.to_string()))
+            })
+            .collect::<Result<Vec<i32>>>()?;
+        let [left, right] = numbers[..] else {
+            return Err(Error::parse_at(contents, line, "expected two numbers"));
+        };
+
+        left_list.push(left);
+        right_list.push(right);
     }
 
+    Ok((left_list, right_list))
+}
+
+pub fn calculate_sim_score(left_list: &[i32], right_list: &[i32]) -> i32 {
     let result = left_list
         .iter()
         .map(|x| {
-            let count: i32 = right_list
-                .iter()
-                .map(|&x| x as i32)
-                .filter(|y| *y == *x)
-                .collect::<Vec<i32>>()
-                .len()
-                .try_into()
-                .unwrap();
-            return x * count;
+            let count = right_list.iter().filter(|y| *y == x).count();
+            x * count as i32
         })
         .sum::<i32>();
 
@@ -49,13 +40,10 @@ mod tests {
     use super::*;
 
     #[test]
-    fn simple_test() {
-        let contents = "\
-1 2
-5 4
-3 1
-1 3
-";
-        assert_eq!(5, calculate_sim_score(contents));
+    fn simple_test() -> Result<()> {
+        let contents = include_str!("../examples/part1.txt");
+        let (left_list, right_list) = parse(contents)?;
+        assert_eq!(31, calculate_sim_score(&left_list, &right_list));
+        Ok(())
     }
 }
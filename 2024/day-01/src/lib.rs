@@ -0,0 +1,29 @@
+mod day_1;
+
+use aoc_core::{Answer, Day, Result, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+        day_1::parse(input)
+    }
+
+    fn part2((left_list, right_list): &(Vec<i32>, Vec<i32>)) -> Result<Answer> {
+        Ok(day_1::calculate_sim_score(left_list, right_list).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day01>(2024, 1, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
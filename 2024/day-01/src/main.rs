@@ -1,9 +1,3 @@
-mod day_1;
-
 fn main() {
-    println!("Day 1");
-    match day_1::run() {
-        Ok(result) => println!("Result: {}", result),
-        Err(e) => println!("Error: {}", e),
-    }
+    aoc_core::main(&aoc_2024_day_01::DAY);
 }
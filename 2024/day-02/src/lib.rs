@@ -0,0 +1,49 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use nom::{
+    character::complete::{self, newline, space1},
+    multi::separated_list1,
+    IResult, Parser,
+};
+
+pub type Report = Vec<i32>;
+
+fn parse(input: &str) -> IResult<&str, Vec<Report>> {
+    separated_list1(newline, separated_list1(space1, complete::i32)).parse(input)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Vec<Report>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(reports: &Vec<Report>) -> Result<Answer> {
+        Ok(part_1::process(reports).into())
+    }
+
+    fn part2(reports: &Vec<Report>) -> Result<Answer> {
+        Ok(part_2::count_safe_reports(reports).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day02>(2024, 2, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
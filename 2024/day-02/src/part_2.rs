@@ -1,30 +1,15 @@
-use std::error::Error;
-use std::fs;
+use crate::Report;
 
-const FILE_PATH: &str = "./input1.txt";
-
-pub fn run() -> Result<i32, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-
-    let sum = count_safe_reports(&contents);
-
-    Ok(sum)
-}
-
-fn count_safe_reports(contents: &str) -> i32 {
-    let result = contents.lines().filter(|line| is_safe_report(line)).count() as i32;
-    result
-}
-
-fn is_safe_report(line: &str) -> bool {
-    let words: Vec<&str> = line.split_whitespace().collect();
-    let numbers = words
+pub fn count_safe_reports(reports: &[Report]) -> usize {
+    reports
         .iter()
-        .map(|x| x.parse::<i32>().unwrap())
-        .collect::<Vec<i32>>();
+        .filter(|numbers| is_safe_report(numbers))
+        .count()
+}
 
-    let all_desc_or_asc = is_desc_or_asc(&numbers);
-    let all_within_bounds = is_all_within_bounds(&numbers);
+fn is_safe_report(numbers: &Report) -> bool {
+    let all_desc_or_asc = is_desc_or_asc(numbers);
+    let all_within_bounds = is_all_within_bounds(numbers);
 
     if all_desc_or_asc && all_within_bounds {
         return true;
@@ -40,10 +25,10 @@ fn is_safe_report(line: &str) -> bool {
         }
     }
 
-    return false;
+    false
 }
 
-fn is_desc_or_asc(numbers: &Vec<i32>) -> bool {
+fn is_desc_or_asc(numbers: &[i32]) -> bool {
     let mut is_desc = true;
     let mut is_asc = true;
     for i in 0..numbers.len() - 1 {
@@ -57,10 +42,10 @@ fn is_desc_or_asc(numbers: &Vec<i32>) -> bool {
     is_desc || is_asc
 }
 
-fn is_all_within_bounds(numbers: &Vec<i32>) -> bool {
+fn is_all_within_bounds(numbers: &[i32]) -> bool {
     for i in 0..numbers.len() - 1 {
         let diff = (numbers[i] - numbers[i + 1]).abs();
-        if diff < 1 || diff > 3 {
+        if !(1..=3).contains(&diff) {
             return false;
         }
     }
@@ -70,17 +55,12 @@ fn is_all_within_bounds(numbers: &Vec<i32>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{parse, EXAMPLE};
 
     #[test]
-    fn count_safe_reports_test() {
-        let contents = "\
-7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9
-";
-        assert_eq!(4, count_safe_reports(contents));
+    fn count_safe_reports_test() -> Result<(), String> {
+        let (_, reports) = parse(EXAMPLE).map_err(|e| e.to_string())?;
+        assert_eq!(4, count_safe_reports(&reports));
+        Ok(())
     }
 }
@@ -0,0 +1,82 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::{parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::anychar,
+    combinator::{map, rest, value},
+    multi::{many0, many_till},
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Parser,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Keyword {
+    Mul(i32),
+    Do,
+    Dont,
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Keyword>> {
+    terminated(
+        many0(map(many_till(anychar, parse_instruction), |value| value.1)),
+        rest,
+    )
+    .parse(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Keyword> {
+    alt((
+        value(Keyword::Do, tag("do()")),
+        value(Keyword::Dont, tag("don't()")),
+        parse_mul,
+    ))
+    .parse(input)
+}
+
+fn parse_mul(input: &str) -> IResult<&str, Keyword> {
+    let (next, _) = tag("mul").parse(input)?;
+
+    let (next, result) = delimited(
+        tag("("),
+        separated_pair(unsigned::<i32>, tag(","), unsigned::<i32>),
+        tag(")"),
+    )
+    .parse(next)?;
+
+    let product = result.0 * result.1;
+    Ok((next, Keyword::Mul(product)))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Vec<Keyword>;
+
+    fn parse(input: &str) -> Result<Vec<Keyword>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(instructions: &Vec<Keyword>) -> Result<Answer> {
+        Ok(part_1::process(instructions).into())
+    }
+
+    fn part2(instructions: &Vec<Keyword>) -> Result<Answer> {
+        Ok(part_2::process(instructions).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day03>(2024, 3, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
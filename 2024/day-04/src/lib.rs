@@ -0,0 +1,43 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_grid::{char_grid, Grid};
+use nom::IResult;
+
+pub type Matrix = Grid<char>;
+
+fn parse(input: &str) -> IResult<&str, Matrix> {
+    char_grid(input)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed<'a> = Matrix;
+
+    fn parse(input: &str) -> Result<Matrix> {
+        parse_all(parse, input)
+    }
+
+    fn part1(matrix: &Matrix) -> Result<Answer> {
+        Ok(part_1::process(matrix).into())
+    }
+
+    fn part2(matrix: &Matrix) -> Result<Answer> {
+        Ok(part_2::count_xmases(matrix).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day04>(2024, 4, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -1,82 +1,34 @@
-use nom::{
-    bytes::complete::is_not, character::complete::newline, combinator::map, multi::separated_list1,
-    IResult,
-};
-use std::error::Error;
-use std::fs;
-
-const FILE_PATH: &str = "./input1.txt";
-
-pub fn run() -> Result<String, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-    match process(&contents) {
-        Ok(result) => {
-            // dbg!(&result);
-            Ok(result.to_string())
-        }
-        Err(e) => Err(e.into()),
-    }
-}
-
-fn process(input: &str) -> Result<u32, String> {
-    match parse(input) {
-        Ok((_, matrix)) => {
-            let sum = count_xmases(&matrix);
-
-            return Ok(sum);
-        }
-        Err(_err) => return Err("failed to parse".to_string()),
-    }
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
-    separated_list1(
-        newline,
-        map(is_not("\n"), |matched: &str| {
-            matched.split("").filter(|&x| x != "").collect()
-        }),
-    )(input)
-}
-
-fn count_xmases(m: &Vec<Vec<&str>>) -> u32 {
-    (0..m.len() - 2)
-        .map(|row| {
-            let count = (0..m[0].len() - 2)
-                .filter(move |col| {
-                    let l_diag = [m[row][*col], m[row + 1][col + 1], m[row + 2][col + 2]].to_vec();
-                    let r_diag = [m[row][col + 2], m[row + 1][col + 1], m[row + 2][*col]].to_vec();
-                    // dbg!(&l_diag);
-                    // dbg!(&r_diag);
-                    is_mas(&l_diag) && is_mas(&r_diag)
-                })
-                .count();
-            return count;
+use crate::Matrix;
+use aoc_geom::Dir8;
+
+pub fn count_xmases(m: &Matrix) -> u32 {
+    m.positions_where(|&c| c == 'A')
+        .filter(|&pos| {
+            let corner = |dir| m.get(pos.step(dir)).copied();
+            is_mas(corner(Dir8::UpLeft), corner(Dir8::DownRight))
+                && is_mas(corner(Dir8::UpRight), corner(Dir8::DownLeft))
         })
-        .sum::<usize>() as u32
+        .count() as u32
 }
 
-fn is_mas(line: &Vec<&str>) -> bool {
-    *line == ["M", "A", "S"].to_vec() || *line == ["S", "A", "M"].to_vec()
+/// Whether the ends of a diagonal through an `A` spell `MAS` either way.
+fn is_mas(start: Option<char>, end: Option<char>) -> bool {
+    matches!(
+        (start, end),
+        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
     fn test_process() -> Result<(), String> {
-        let contents = "\
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-        assert_eq!(9, process(contents)?);
+        let contents = include_str!("../examples/part1.txt");
+        let (_, matrix) = parse(contents).map_err(|e| e.to_string())?;
+        assert_eq!(9, count_xmases(&matrix));
         Ok(())
     }
 }
@@ -0,0 +1,101 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::{blank_line, comma_list, lines_of, parse_all};
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_graph::Graph;
+use nom::{
+    bytes::complete::tag, character::complete::digit1, combinator::map, sequence::separated_pair,
+    IResult, Parser,
+};
+
+pub type Page<'a> = &'a str;
+pub type OrderingRule<'a> = (Page<'a>, Page<'a>);
+pub type PageList<'a> = Vec<Page<'a>>;
+
+/// The ordering rules as edges from each page to a page that must come
+/// after it, and the updates to check.
+type Manual<'a> = (Graph<'a>, Vec<PageList<'a>>);
+
+fn parse(input: &str) -> IResult<&str, Manual<'_>> {
+    map(
+        separated_pair(lines_of(ordering_rule), blank_line, lines_of(page_list)),
+        |(ordering_rules, page_lists)| (ordering_rules.into_iter().collect(), page_lists),
+    )
+    .parse(input)
+}
+
+fn ordering_rule(input: &str) -> IResult<&str, OrderingRule<'_>> {
+    separated_pair(digit1, tag("|"), digit1).parse(input)
+}
+
+fn page_list(input: &str) -> IResult<&str, PageList<'_>> {
+    comma_list(digit1).parse(input)
+}
+
+/// Whether no rule puts a page of `page_list` before one listed ahead of
+/// it. Pages no rule relates may be in any order.
+fn in_order(ordering_rules: &Graph, page_list: &PageList) -> bool {
+    let must_precede = |before: Page, after: Page| {
+        ordering_rules
+            .id(before)
+            .zip(ordering_rules.id(after))
+            .is_some_and(|(before, after)| ordering_rules.successors(before).contains(&after))
+    };
+    page_list.iter().enumerate().all(|(i, &later)| {
+        page_list[..i]
+            .iter()
+            .all(|&earlier| !must_precede(later, earlier))
+    })
+}
+
+/// The pages of `page_list` in the order the rules between them demand.
+fn sort<'a>(ordering_rules: &Graph<'a>, page_list: &PageList<'a>) -> Result<PageList<'a>> {
+    let rules = ordering_rules.subgraph(page_list.iter().copied());
+    let order = rules.topological_order()?;
+    Ok(order.into_iter().map(|id| rules.name(id)).collect())
+}
+
+fn middle_element(list: &[&str]) -> usize {
+    let idx = list.len() / 2;
+    list[idx].parse::<usize>().unwrap_or_default()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = Manual<'a>;
+
+    fn parse(input: &str) -> Result<Manual<'_>> {
+        parse_all(parse, input)
+    }
+
+    fn part1((ordering_rules, page_lists): &Manual<'_>) -> Result<Answer> {
+        Ok(part_1::process(ordering_rules, page_lists).into())
+    }
+
+    fn part2((ordering_rules, page_lists): &Manual<'_>) -> Result<Answer> {
+        Ok(part_2::process(ordering_rules, page_lists)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day05>(2024, 5, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pages_without_rules_stay_in_order() -> Result<()> {
+        let manual = Day05::parse("47|53\n97|13\n\n47,53\n11,22,33\n")?;
+
+        assert_eq!(Day05::part1(&manual)?, Answer::Number(75));
+        assert_eq!(Day05::part2(&manual)?, Answer::Number(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
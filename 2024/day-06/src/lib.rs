@@ -0,0 +1,81 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_geom::{Dir4, Point2};
+use aoc_grid::{parse_grid, Grid};
+use nom::IResult;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    Empty,
+    Obsruction,
+    Guard(Dir4),
+    Path,
+}
+
+pub type Lab = Grid<Position>;
+
+fn parse(input: &str) -> IResult<&str, Lab> {
+    parse_grid(parse_lab_space)(input)
+}
+
+fn parse_lab_space(c: char) -> Option<Position> {
+    match c {
+        '.' => Some(Position::Empty),
+        '#' => Some(Position::Obsruction),
+        '^' => Some(Position::Guard(Dir4::Up)),
+        'v' => Some(Position::Guard(Dir4::Down)),
+        '<' => Some(Position::Guard(Dir4::Left)),
+        '>' => Some(Position::Guard(Dir4::Right)),
+        'X' => Some(Position::Path),
+        _ => None,
+    }
+}
+
+fn find_guard(lab: &Lab) -> Option<(Point2, Dir4)> {
+    lab.iter().find_map(|(pos, cell)| match cell {
+        Position::Guard(dir) => Some((pos, *dir)),
+        _ => None,
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = Lab;
+
+    fn parse(input: &str) -> Result<Lab> {
+        parse_all(parse, input)
+    }
+
+    fn part1(lab: &Lab) -> Result<Answer> {
+        Ok(part_1::process(lab)?.into())
+    }
+
+    fn part2(lab: &Lab) -> Result<Answer> {
+        Ok(part_2::process(lab)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day06>(2024, 6, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_guard_is_an_error() -> Result<()> {
+        let lab = Day06::parse("v.\nv.\n")?;
+
+        assert!(Day06::part1(&lab).is_err());
+        assert!(Day06::part2(&lab).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
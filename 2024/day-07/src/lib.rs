@@ -0,0 +1,52 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::parse::{lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use nom::{
+    bytes::complete::tag, character::complete::space1, multi::separated_list1, IResult, Parser,
+};
+
+pub type Equation = (u64, Vec<u64>);
+
+fn parse(input: &str) -> IResult<&str, Vec<Equation>> {
+    lines_of(equation).parse(input)
+}
+
+fn equation(input: &str) -> IResult<&str, Equation> {
+    let (next, result_value) = unsigned(input)?;
+    let (next, _) = tag(": ").parse(next)?;
+    let (input, inputs) = separated_list1(space1, unsigned).parse(next)?;
+    let result = (result_value, inputs);
+    Ok((input, result))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Vec<Equation>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Result<Answer> {
+        Ok(part_1::process(equations).into())
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Result<Answer> {
+        Ok(part_2::process(equations).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day07>(2024, 7, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
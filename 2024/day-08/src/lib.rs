@@ -0,0 +1,106 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::{Answer, Day, Error, Result, Solution};
+use aoc_geom::Point2;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    multi::{many0, many1},
+    IResult, Parser,
+};
+use nom_locate::LocatedSpan;
+use std::collections::HashMap;
+
+type Span<'a> = LocatedSpan<&'a str>;
+pub type Frequency<'a> = &'a str;
+pub type Position = Point2;
+pub type AntennaMap<'a> = HashMap<Frequency<'a>, Vec<Position>>;
+
+/// The antennas along with the size of the map they sit on.
+pub struct City<'a> {
+    pub rows: i64,
+    pub cols: i64,
+    pub antennas: AntennaMap<'a>,
+}
+
+impl City<'_> {
+    pub fn contains(&self, pos: Position) -> bool {
+        (0..self.cols).contains(&pos.x) && (0..self.rows).contains(&pos.y)
+    }
+}
+
+fn parse(input: Span) -> IResult<Span, AntennaMap> {
+    let (next, antennas) = many1(antenna).parse(input)?;
+
+    let mut antenna_map = HashMap::new();
+    for (frequency, position) in antennas {
+        antenna_map
+            .entry(frequency)
+            .or_insert_with(Vec::new)
+            .push(position);
+    }
+
+    let (next, _) = many0(alt((tag("."), tag("\n")))).parse(next)?;
+    Ok((next, antenna_map))
+}
+
+fn antenna(input: Span<'_>) -> IResult<Span<'_>, (&str, Position)> {
+    let (next, _) = many0(alt((tag("."), tag("\n")))).parse(input)?;
+
+    let row = next.location_line();
+    let col = next.get_column();
+    let (next, antenna) = take(1usize).parse(next)?;
+
+    let antenna_str = *antenna.fragment();
+    Ok((
+        next,
+        (antenna_str, Point2::new(col as i64 - 1, row as i64 - 1)),
+    ))
+}
+
+fn parse_city(input: &str) -> Result<City<'_>> {
+    let rows = input.lines().count() as i64;
+    let cols = input
+        .lines()
+        .next()
+        .ok_or_else(|| Error::parse_at(input, input, "empty input"))?
+        .len() as i64;
+    let (_remaining, antennas) = parse(Span::new(input))
+        .map_err(|e| Error::from_nom(input, e.map_input(|span| *span.fragment())))?;
+    Ok(City {
+        rows,
+        cols,
+        antennas,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = City<'a>;
+
+    fn parse(input: &str) -> Result<City<'_>> {
+        parse_city(input)
+    }
+
+    fn part1(city: &City<'_>) -> Result<Answer> {
+        Ok(part_1::process(city).into())
+    }
+
+    fn part2(city: &City<'_>) -> Result<Answer> {
+        Ok(part_2::process(city).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day08>(2024, 8, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -1,2 +1,45 @@
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Answer, Day, Error, Result, Solution};
+
+/// Alternating file and free-space lengths, as given by the puzzle input.
+pub type DiskMap = Vec<usize>;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = DiskMap;
+
+    fn parse(input: &str) -> Result<DiskMap> {
+        let disk_map = input.trim_end();
+        disk_map
+            .char_indices()
+            .map(|(idx, x)| {
+                x.to_digit(10)
+                    .map(|count| count as usize)
+                    .ok_or_else(|| Error::parse_at(disk_map, &disk_map[idx..], "expected a digit"))
+            })
+            .collect()
+    }
+
+    fn part1(disk_map: &DiskMap) -> Result<Answer> {
+        Ok(part1::process(disk_map)?.into())
+    }
+
+    fn part2(disk_map: &DiskMap) -> Result<Answer> {
+        Ok(part2::process(disk_map)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day09>(2024, 9, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
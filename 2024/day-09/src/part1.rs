@@ -1,17 +1,4 @@
-use std::error::Error;
-use std::fs;
-
-const FILE_PATH: &str = "./input.txt";
-
-pub fn run() -> Result<String, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-    dbg!(&contents);
-
-    match process(&contents) {
-        Ok(result) => Ok(result.to_string()),
-        Err(e) => Err(e.into()),
-    }
-}
+use aoc_core::Result;
 
 type Id = usize;
 
@@ -22,22 +9,19 @@ enum Disk {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> Result<usize, String> {
-    let mut disk: Vec<Disk> = input
-        .trim()
-        .chars()
+pub fn process(disk_map: &[usize]) -> Result<usize> {
+    let mut disk: Vec<Disk> = disk_map
+        .iter()
         .enumerate()
-        .flat_map(|(idx, x)| {
-            let count = x.to_digit(10).unwrap() as usize;
+        .flat_map(|(idx, &count)| {
             if idx % 2 == 0 {
                 let id = idx / 2;
-                return vec![Disk::FileBlock(id); count];
+                vec![Disk::FileBlock(id); count]
             } else {
-                return vec![Disk::FreeSpace; count];
+                vec![Disk::FreeSpace; count]
             }
         })
         .collect();
-    // dbg!(&unformatted_disk);
 
     let mut freeing_idx: usize = disk.len() - 1;
     let mut move_to_idx: usize = 0;
@@ -61,53 +45,36 @@ pub fn process(input: &str) -> Result<usize, String> {
         }
     }
 
-    // dbg!(&disk);
-    // print_disk(&disk);
-
     let result = disk
         .into_iter()
         .enumerate()
         .fold(0, |acc, (idx, x)| match x {
-            Disk::FileBlock(id) => {
-                return acc + id * idx;
-            }
-            Disk::FreeSpace => {
-                return acc;
-            }
+            Disk::FileBlock(id) => acc + id * idx,
+            Disk::FreeSpace => acc,
         });
 
-    return Ok(result);
-}
-
-fn print_disk(disk: &Vec<Disk>) {
-    for x in disk {
-        match x {
-            Disk::FileBlock(id) => {
-                print!("{}", id);
-            }
-            Disk::FreeSpace => {
-                print!("-");
-            }
-        }
-    }
-    println!();
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day09;
+    use aoc_core::Solution;
 
     #[test]
-    fn test_process() -> Result<(), String> {
+    fn test_process() -> Result<()> {
         let contents = "12345";
-        assert_eq!(60, process(contents)?);
+        let disk_map = Day09::parse(contents)?;
+        assert_eq!(60, process(&disk_map)?);
         Ok(())
     }
 
     #[test]
-    fn test_process_2() -> Result<(), String> {
-        let contents = "2333133121414131402";
-        assert_eq!(1928, process(contents)?);
+    fn test_process_2() -> Result<()> {
+        let contents = include_str!("../examples/part1.txt");
+        let disk_map = Day09::parse(contents)?;
+        assert_eq!(1928, process(&disk_map)?);
         Ok(())
     }
 }
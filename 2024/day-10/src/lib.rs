@@ -0,0 +1,59 @@
+mod part1;
+mod part2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_geom::Point2;
+use aoc_grid::{parse_grid, Grid};
+use nom::IResult;
+
+pub type Position = Point2;
+pub type Elevation = i32;
+pub type Topo = Grid<Elevation>;
+
+fn parse(input: &str) -> IResult<&str, Topo> {
+    parse_grid(|c| c.to_digit(10).map(|d| d as Elevation))(input)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Topo;
+
+    fn parse(input: &str) -> Result<Topo> {
+        parse_all(parse, input)
+    }
+
+    fn part1(topo: &Topo) -> Result<Answer> {
+        Ok(part1::process(topo).into())
+    }
+
+    fn part2(topo: &Topo) -> Result<Answer> {
+        Ok(part2::process(topo).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day10>(2024, 10, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_newline_adds_no_row() -> Result<()> {
+        let topo = "0123\n1234\n8765\n9876";
+
+        for input in [topo.to_string(), format!("{}\n", topo)] {
+            let parsed = Day10::parse(&input)?;
+            assert_eq!(parsed.height(), 4);
+            assert_eq!(Day10::part1(&parsed)?, Answer::Number(1));
+            assert_eq!(Day10::part2(&parsed)?, Answer::Number(16));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
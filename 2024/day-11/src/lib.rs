@@ -0,0 +1,45 @@
+mod part1;
+mod part2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use nom::{
+    character::complete::{self, multispace0, multispace1},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+fn parse(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(multispace0, separated_list1(multispace1, complete::u64)).parse(input)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(stones: &Vec<u64>) -> Result<Answer> {
+        Ok(part1::process(stones)?.into())
+    }
+
+    fn part2(stones: &Vec<u64>) -> Result<Answer> {
+        Ok(part2::process(stones).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day11>(2024, 11, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
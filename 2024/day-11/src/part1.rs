@@ -1,83 +1,69 @@
-use std::error::Error;
-use std::fs;
-
-const FILE_PATH: &str = "./input.txt";
-
-pub fn run() -> Result<String, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-    match process(&contents) {
-        Ok(result) => Ok(result.to_string()),
-        Err(e) => Err(e.into()),
-    }
-}
+use aoc_core::{Error, Result};
 
 type Stone = String;
 type Stones = Vec<Stone>;
 
-fn process(input: &str) -> Result<u32, String> {
-    let mut stones = input
-        .split_whitespace()
+pub fn process(initial_stones: &[u64]) -> Result<u32> {
+    let mut stones = initial_stones
+        .iter()
         .map(|s| s.to_string())
         .collect::<Stones>();
 
-    for x in 0..25 {
-        // print_stones(&stones);
-        println!("{} {}", x, stones.len());
-        stones = blink(stones);
+    for _ in 0..25 {
+        stones = blink(stones)?;
     }
 
     Ok(stones.len() as u32)
 }
 
-fn blink(stones: Stones) -> Stones {
-    stones
-        .iter()
-        .flat_map(|stone| process_stone(stone.clone()))
-        .collect()
+fn blink(stones: Stones) -> Result<Stones> {
+    let mut next = Vec::with_capacity(stones.len());
+    for stone in stones {
+        next.extend(process_stone(stone)?);
+    }
+    Ok(next)
 }
 
-fn process_stone(stone: Stone) -> Stones {
+fn process_stone(stone: Stone) -> Result<Stones> {
     if stone == "0" {
-        return vec!["1".to_string()];
+        return Ok(vec!["1".to_string()]);
     }
 
-    if stone.len() % 2 == 0 {
+    if stone.len().is_multiple_of(2) {
         let left = &stone[..stone.len() / 2];
         let right = remove_leading_zeros(&stone[stone.len() / 2..]);
 
-        return vec![left.to_string(), right.to_string()];
+        return Ok(vec![left.to_string(), right.to_string()]);
     }
 
-    let next = (stone.parse::<u64>().unwrap() * 2024).to_string();
+    let next = stone
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(2024))
+        .ok_or_else(|| Error::invariant(format!("stone {} times 2024 overflows", stone)))?;
 
-    vec![next]
+    Ok(vec![next.to_string()])
 }
 
 fn remove_leading_zeros(input: &str) -> String {
     let result = input.trim_start_matches('0').to_string();
     if result.is_empty() {
-        return "0".to_string();
+        "0".to_string()
     } else {
-        return result;
+        result
     }
 }
 
-fn print_stones(stones: &Stones) {
-    let stones = stones
-        .iter()
-        .map(|stone| stone.to_string())
-        .collect::<Vec<String>>();
-    println!("{}", stones.join(" "));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
     fn test_process() -> Result<(), String> {
-        let contents = "125 17";
-        assert_eq!(55312, process(contents)?);
+        let contents = include_str!("../examples/part1.txt");
+        let (_, stones) = parse(contents).map_err(|e| e.to_string())?;
+        assert_eq!(55312, process(&stones).map_err(|e| e.to_string())?);
         Ok(())
     }
 }
@@ -1,19 +1,6 @@
-use std::collections::HashMap;
-use std::error::Error;
-use std::fs;
-
-const FILE_PATH: &str = "./input.txt";
-
-pub fn run() -> Result<String, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-    match process(&contents) {
-        Ok(result) => Ok(result.to_string()),
-        Err(e) => Err(e.into()),
-    }
-}
+use aoc_memo::Memo;
 
 type Stone = u64;
-type Stones = Vec<u64>;
 enum NextStones {
     Single(Stone),
     Double(Stone, Stone),
@@ -22,75 +9,31 @@ enum NextStones {
 type Iteration = usize;
 type Value = u64;
 type StoneIteration = (Stone, Iteration);
-type CountCache = HashMap<StoneIteration, Value>;
-
-fn process(input: &str) -> Result<u64, String> {
-    let stones = input
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect::<Stones>();
-
-    let mut count_cache: CountCache = HashMap::new();
-    count_cache.insert((0, 1), 1);
-    count_cache.insert((0, 0), 1);
-    count_cache.insert((1, 0), 1);
-    count_cache.insert((1, 1), 1);
-    count_cache.insert((2024, 0), 1);
-    count_cache.insert((2024, 1), 2);
 
+pub fn process(stones: &[u64]) -> u64 {
     let iterations = 75;
 
-    let result = stones.iter().fold(0, |acc, stone| {
-        let stone_iteration: StoneIteration = (*stone, iterations);
-        let result = calculate_value(stone_iteration, &mut count_cache);
-        acc + result
-    });
-
-    Ok(result)
+    let mut memo = Memo::new();
+    stones
+        .iter()
+        .map(|&stone| count_stones(&mut memo, (stone, iterations)))
+        .sum()
 }
 
-fn calculate_value(stone_iteration: StoneIteration, count_cache: &mut CountCache) -> Value {
-    // println!("stone: {} iteration: {}", stone_iteration.0, stone_iteration.1);
-    // dbg!(&count_cache);
-    if count_cache.contains_key(&stone_iteration) {
-        let value = count_cache.get(&stone_iteration).unwrap();
-        // println!("found: {:?} -> {}", &stone_iteration, value);
-        return *value;
-    }
-
-    let (stone, iteration) = stone_iteration;
-
-    if iteration == 0usize {
-        // println!("No more iterations: {:?}", &stone_iteration);
-        count_cache.insert(stone_iteration, 1);
-        return 1;
-    }
-
-    let next_stones = process_stone(&stone);
-
-    match next_stones {
-        NextStones::Single(next_stone) => {
-            let next_stone_iteration = (next_stone, iteration - 1);
-            let next_value = calculate_value(next_stone_iteration, count_cache);
-
-            count_cache.insert(stone_iteration, next_value);
-
-            return next_value;
+/// How many stones `stone` turns into after `iteration` more blinks.
+fn count_stones(memo: &mut Memo<StoneIteration, Value>, stone_iteration: StoneIteration) -> Value {
+    memo.get_or_compute(stone_iteration, |memo, &(stone, iteration)| {
+        if iteration == 0 {
+            return 1;
         }
-        NextStones::Double(left_stone, right_stone) => {
-            let left_stone_iteration = (left_stone, iteration - 1);
-            let left_value = calculate_value(left_stone_iteration, count_cache);
-
-            let right_stone_iteration = (right_stone, iteration - 1);
-            let right_value = calculate_value(right_stone_iteration, count_cache);
-
-            let next_value = left_value + right_value;
-
-            count_cache.insert(stone_iteration, next_value);
-
-            return next_value;
+        match process_stone(&stone) {
+            NextStones::Single(next_stone) => count_stones(memo, (next_stone, iteration - 1)),
+            NextStones::Double(left_stone, right_stone) => {
+                count_stones(memo, (left_stone, iteration - 1))
+                    + count_stones(memo, (right_stone, iteration - 1))
+            }
         }
-    }
+    })
 }
 
 fn process_stone(stone: &Stone) -> NextStones {
@@ -98,38 +41,26 @@ fn process_stone(stone: &Stone) -> NextStones {
         return NextStones::Single(1);
     }
 
-    let stone_str = format!("{}", stone);
-    let stone_len = stone_str.len();
-    if stone_len % 2 == 0 {
-        let left = (&stone_str[..stone_len / 2]).parse::<u64>().unwrap();
-        let right = (remove_leading_zeros(&stone_str[stone_len / 2..]))
-            .parse::<u64>()
-            .unwrap();
-
-        return NextStones::Double(left, right);
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+        return NextStones::Double(stone / half, stone % half);
     }
 
     let next = stone * 2024;
-    return NextStones::Single(next);
-}
-
-fn remove_leading_zeros(input: &str) -> &str {
-    let result = input.trim_start_matches('0');
-    if result.is_empty() {
-        return "0";
-    } else {
-        return result;
-    }
+    NextStones::Single(next)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
     fn test_process() -> Result<(), String> {
-        let contents = "125 17";
-        assert_eq!(65601038650482, process(contents)?);
+        let contents = include_str!("../examples/part1.txt");
+        let (_, stones) = parse(contents).map_err(|e| e.to_string())?;
+        assert_eq!(65601038650482, process(&stones));
         Ok(())
     }
 }
@@ -0,0 +1,40 @@
+mod part1;
+// mod part2;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use nom::{bytes::complete::tag, multi::many0, IResult, Parser};
+
+fn parse(input: &str) -> IResult<&str, Vec<&str>> {
+    many0(tag("advent")).parse(input)
+}
+
+pub struct Day{{dd}};
+
+impl Solution for Day{{dd}} {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(parsed: &Vec<&str>) -> Result<Answer> {
+        Ok(part1::process(parsed).into())
+    }
+
+    // fn part2(parsed: &Vec<&str>) -> Result<Answer> {
+    //     Ok(part2::process(parsed).into())
+    // }
+}
+
+pub const DAY: Day = Day::new::<Day{{dd}}>({{year}}, {{day}}, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
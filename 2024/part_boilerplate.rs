@@ -1,43 +1,18 @@
-use nom::{
-    bytes::complete::tag,
-    multi::many0,
-    IResult,
-};
-use std::error::Error;
-use std::fs;
-
-const FILE_PATH: &str = "./input.txt";
-
-pub fn run() -> Result<String, Box<dyn Error>> {
-    let contents = fs::read_to_string(FILE_PATH)?;
-    match process(&contents) {
-        Ok(result) => Ok(result.to_string()),
-        Err(e) => Err(e.into()),
-    }
-}
-
-fn process(input: &str) -> Result<u32, String> {
-    match parse(input) {
-        Ok((_remaining, results)) => {
-            // dbg!(&results);
-            Ok(12)
-        }
-        Err(_err) => Err("parsing failed".to_string()),
-    }
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<&str>> {
-    many0(tag("advent"))(input)
+pub fn process(parsed: &[&str]) -> usize {
+    // dbg!(&parsed);
+    parsed.len()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
     fn test_process() -> Result<(), String> {
-        let contents = "12";
-        assert_eq!(12, process(contents)?);
+        let contents = include_str!("../examples/part1.txt");
+        let (_, parsed) = parse(contents).map_err(|e| e.to_string())?;
+        assert_eq!(2, process(&parsed));
         Ok(())
     }
 }
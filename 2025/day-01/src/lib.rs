@@ -0,0 +1,214 @@
+use nom::Parser;
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::pair,
+};
+
+use aoc_core::parse::{lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rotation {
+    dir: Direction,
+    steps: i32,
+}
+
+fn rot(dir: Direction, steps: i32) -> Rotation {
+    Rotation { dir, steps }
+}
+
+fn direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        value(Direction::Left, tag("L")),
+        value(Direction::Right, tag("R")),
+    ))
+    .parse(input)
+}
+
+fn rotation(input: &str) -> IResult<&str, Rotation> {
+    map(pair(direction, unsigned), |(dir, steps)| rot(dir, steps)).parse(input)
+}
+
+fn rotations(input: &str) -> IResult<&str, Vec<Rotation>> {
+    lines_of(rotation).parse(input)
+}
+
+fn rotate_dial(dial_size: i32, dial_pos: i32, rotation: &Rotation) -> (i32, i32) {
+    debug_assert!(dial_size > 0);
+    debug_assert!(dial_pos < dial_size);
+
+    let steps = rotation.steps;
+
+    let full_spins = steps.div_euclid(dial_size);
+
+    let step_mod = steps.rem_euclid(dial_size);
+
+    let delta = match rotation.dir {
+        Direction::Left => -step_mod,
+        Direction::Right => step_mod,
+    };
+
+    let next = (dial_pos + delta).rem_euclid(dial_size);
+
+    let crossed_zero = dial_pos != 0
+        && match rotation.dir {
+            Direction::Left => next > dial_pos || next == 0,
+            Direction::Right => next < dial_pos || next == 0,
+        };
+
+    (next, full_spins + i32::from(crossed_zero))
+}
+
+fn count_zero_stops(rotations: &[Rotation]) -> i32 {
+    let dial_size = 100;
+
+    let (_final_pos, zero_stops) =
+        rotations
+            .iter()
+            .fold((50i32, 0i32), |(dial_pos, zero_stops), rotation| {
+                let (next_dial_pos, _zeros_hit) = rotate_dial(dial_size, dial_pos, rotation);
+
+                (next_dial_pos, zero_stops + i32::from(next_dial_pos == 0))
+            });
+
+    zero_stops
+}
+
+fn find_password(rotations: &[Rotation]) -> i32 {
+    let dial_size = 100;
+
+    let (_final_pos, zeros_hit) =
+        rotations
+            .iter()
+            .fold((50i32, 0i32), |(dial_pos, zeros_hit), rotation| {
+                let (next_dial_pos, next_zeros_hit) = rotate_dial(dial_size, dial_pos, rotation);
+
+                (next_dial_pos, zeros_hit + next_zeros_hit)
+            });
+
+    zeros_hit
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Vec<Rotation>> {
+        parse_all(rotations, input)
+    }
+
+    fn part1(rotations: &Vec<Rotation>) -> Result<Answer> {
+        Ok(count_zero_stops(rotations).into())
+    }
+
+    fn part2(rotations: &Vec<Rotation>) -> Result<Answer> {
+        Ok(find_password(rotations).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day01>(2025, 1, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = "
+L68
+R48
+";
+
+        let expected: Vec<Rotation> = vec![rot(Direction::Left, 68), rot(Direction::Right, 48)];
+
+        let parsed = parse_all(rotations, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    // The dial starts by pointing at 50.
+    //
+    // 1 The dial is rotated L68 to point at 82; during this rotation, it points at 0 once.
+    // 2 The dial is rotated L30 to point at 52.
+    // 3 The dial is rotated R48 to point at 0.
+    // 4 The dial is rotated L5 to point at 95.
+    // 5 The dial is rotated R60 to point at 55; during this rotation, it points at 0 once.
+    // 6 The dial is rotated L55 to point at 0.
+    // 7 The dial is rotated L1 to point at 99.
+    // 8 The dial is rotated L99 to point at 0.
+    // 9 The dial is rotated R14 to point at 14.
+    // 10 The dial is rotated L82 to point at 32; during this rotation, it points at 0 once.
+
+    #[test]
+    fn test_rotate_dial() {
+        let dial_size = 100;
+
+        let (pos1, hit1) = rotate_dial(dial_size, 50, &rot(Direction::Left, 68));
+        assert_eq!((pos1, hit1), (82, 1), "step 1");
+
+        let (pos2, hit2) = rotate_dial(dial_size, 82, &rot(Direction::Left, 30));
+        assert_eq!((pos2, hit2), (52, 0), "step 2");
+
+        let (pos3, hit3) = rotate_dial(dial_size, 52, &rot(Direction::Right, 48));
+        assert_eq!((pos3, hit3), (0, 1), "step 3");
+
+        let (pos4, hit4) = rotate_dial(dial_size, 0, &rot(Direction::Left, 5));
+        assert_eq!((pos4, hit4), (95, 0), "step 4");
+
+        let (pos5, hit5) = rotate_dial(dial_size, 95, &rot(Direction::Right, 60));
+        assert_eq!((pos5, hit5), (55, 1), "step 5");
+
+        let (pos6, hit6) = rotate_dial(dial_size, 55, &rot(Direction::Left, 55));
+        assert_eq!((pos6, hit6), (0, 1), "step 6");
+
+        let (pos7, hit7) = rotate_dial(dial_size, 0, &rot(Direction::Left, 1));
+        assert_eq!((pos7, hit7), (99, 0), "step 7");
+
+        let (pos8, hit8) = rotate_dial(dial_size, 99, &rot(Direction::Left, 99));
+        assert_eq!((pos8, hit8), (0, 1), "step 8");
+
+        let (pos9, hit9) = rotate_dial(dial_size, 0, &rot(Direction::Right, 14));
+        assert_eq!((pos9, hit9), (14, 0), "step 9");
+
+        let (pos10, hit10) = rotate_dial(dial_size, 14, &rot(Direction::Left, 82));
+        assert_eq!((pos10, hit10), (32, 1), "step 10");
+    }
+
+    #[test]
+    fn test_day_1_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let rotations = parse_all(rotations, input)?;
+
+        let result = count_zero_stops(&rotations);
+        let expected = 3;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_1_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let rotations = parse_all(rotations, input)?;
+
+        let result = find_password(&rotations);
+        let expected = 6;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
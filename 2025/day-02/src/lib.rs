@@ -0,0 +1,344 @@
+use nom::IResult;
+use nom::Parser;
+use std::iter::Sum;
+use std::ops::{AddAssign, RangeInclusive, SubAssign};
+
+use aoc_core::parse::{comma_list, parse_all, range, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_interval::IntervalSet;
+
+fn parse(input: &str) -> IResult<&str, IntervalSet> {
+    comma_list(range(unsigned))
+        .map(IntervalSet::from_iter)
+        .parse(input)
+}
+
+/// How many IDs matched and what they add up to, with room for ranges
+/// spanning the whole of `u64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub count: u128,
+    pub sum: u128,
+}
+
+impl AddAssign for Tally {
+    fn add_assign(&mut self, other: Tally) {
+        self.count += other.count;
+        self.sum += other.sum;
+    }
+}
+
+impl SubAssign for Tally {
+    fn sub_assign(&mut self, other: Tally) {
+        self.count -= other.count;
+        self.sum -= other.sum;
+    }
+}
+
+impl Sum for Tally {
+    fn sum<I: Iterator<Item = Tally>>(iter: I) -> Tally {
+        iter.fold(Tally::default(), |mut acc, t| {
+            acc += t;
+            acc
+        })
+    }
+}
+
+fn digits(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// The `len`-digit IDs in `lo..=hi` made of a `period`-digit block repeated,
+/// which `period` must divide `len` for.
+///
+/// Such an ID is the block times `1 0..01 0..01...`, so the blocks that land
+/// in range form one run and add up as an arithmetic series.
+fn periodic(lo: u64, hi: u64, len: u32, period: u32) -> Tally {
+    let repunit = (10u128.pow(len) - 1) / (10u128.pow(period) - 1);
+    let first = (lo as u128).div_ceil(repunit).max(10u128.pow(period - 1));
+    let last = (hi as u128 / repunit).min(10u128.pow(period) - 1);
+    if first > last {
+        return Tally::default();
+    }
+    let count = last - first + 1;
+    Tally {
+        count,
+        sum: repunit * (first + last) * count / 2,
+    }
+}
+
+/// Like [`periodic`], but only IDs whose shortest repeating block is exactly
+/// `period` digits, so that `111111` is counted once rather than for each
+/// of its periods 1, 2 and 3.
+fn primitive(lo: u64, hi: u64, len: u32, period: u32) -> Tally {
+    let mut tally = periodic(lo, hi, len, period);
+    for shorter in (1..period).filter(|p| period.is_multiple_of(*p)) {
+        tally -= primitive(lo, hi, len, shorter);
+    }
+    tally
+}
+
+/// Which IDs are invalid, by how many times a block of digits repeats to
+/// make them up. `123123123` is `123` three times, and `1111` is both `1`
+/// four times and `11` twice.
+#[derive(Clone, Copy, Debug)]
+pub enum Policy {
+    /// Some block repeated exactly this many times.
+    Exactly(u32),
+    /// Some block repeated this many times or more.
+    AtLeast(u32),
+    /// Some block repeated a number of times the predicate accepts. Only
+    /// counts of two or more are asked about.
+    Custom(fn(u32) -> bool),
+}
+
+impl Policy {
+    fn accepts(self, repeats: u32) -> bool {
+        match self {
+            Policy::Exactly(n) => repeats == n,
+            Policy::AtLeast(n) => repeats >= n,
+            Policy::Custom(predicate) => predicate(repeats),
+        }
+    }
+
+    /// Whether a `len`-digit ID whose shortest block is `period` digits is
+    /// invalid. Its blocks are the multiples of `period` dividing `len`, so
+    /// it can be read as repeating any divisor of `len / period` times.
+    fn rejects(self, len: u32, period: u32) -> bool {
+        let most = len / period;
+        (2..=most)
+            .filter(|repeats| most.is_multiple_of(*repeats))
+            .any(|repeats| self.accepts(repeats))
+    }
+}
+
+/// The IDs in `id_range` that `policy` rejects, enumerated arithmetically
+/// by length and period rather than one by one.
+fn invalid_in_range(id_range: RangeInclusive<u64>, policy: Policy) -> Tally {
+    let (lo, hi) = id_range.into_inner();
+    if lo > hi {
+        return Tally::default();
+    }
+    (digits(lo)..=digits(hi))
+        .flat_map(|len| {
+            (1..len)
+                .filter(move |p| len.is_multiple_of(*p) && policy.rejects(len, *p))
+                .map(move |period| primitive(lo, hi, len, period))
+        })
+        .sum()
+}
+
+fn solve(ids: &IntervalSet, policy: Policy) -> u128 {
+    ids.iter()
+        .map(|range| invalid_in_range(range, policy))
+        .sum::<Tally>()
+        .sum
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = IntervalSet;
+
+    fn parse(input: &str) -> Result<IntervalSet> {
+        parse_all(parse, input)
+    }
+
+    fn part1(id_ranges: &IntervalSet) -> Result<Answer> {
+        Ok(solve(id_ranges, Policy::Exactly(2)).into())
+    }
+
+    fn part2(id_ranges: &IntervalSet) -> Result<Answer> {
+        Ok(solve(id_ranges, Policy::AtLeast(2)).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day02>(2025, 2, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The policy checked one ID at a time, to compare against.
+    fn is_valid_id(id: &str, policy: Policy) -> bool {
+        let bytes = id.as_bytes();
+        let n = bytes.len();
+        let mid = n / 2;
+
+        for chunk_size in 1..=mid {
+            if !n.is_multiple_of(chunk_size) || !policy.accepts((n / chunk_size) as u32) {
+                continue;
+            }
+
+            let pattern = &bytes[..chunk_size];
+            let repeats = bytes.chunks_exact(chunk_size).all(|chunk| chunk == pattern);
+
+            if repeats {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        // Sorted by start, none of the example's ranges overlap.
+        let expected = vec![
+            11..=22,
+            95..=115,
+            998..=1012,
+            222220..=222224,
+            446443..=446449,
+            565653..=565659,
+            1698522..=1698528,
+            38593856..=38593862,
+            824824821..=824824827,
+            1188511880..=1188511890,
+            2121212118..=2121212124,
+        ];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed.iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_valid_id() {
+        // Each ID with whether it is valid for part 1 and for part 2.
+        let cases = [
+            ("11", false, false),
+            ("55", false, false),
+            ("6464", false, false),
+            ("423423", false, false),
+            ("0505", false, false),
+            ("606", true, true),
+            ("1234", true, true),
+            ("121212", true, false),
+            ("111", true, false),
+            ("1111", false, false),
+        ];
+
+        for (id, part_1, part_2) in cases {
+            assert_eq!(is_valid_id(id, Policy::Exactly(2)), part_1, "id: {}", id);
+            assert_eq!(is_valid_id(id, Policy::AtLeast(2)), part_2, "id: {}", id);
+        }
+    }
+
+    #[test]
+    fn test_invalid_in_range() {
+        // Each range with its invalid ID sum for part 1 and for part 2.
+        let cases = [
+            (11..=22, 11 + 22, 11 + 22),
+            (95..=115, 99, 99 + 111),
+            (998..=1012, 1010, 999 + 1010),
+            (1188511880..=1188511890, 1188511885, 1188511885),
+            (1698522..=1698528, 0, 0),
+            (565653..=565659, 0, 565656),
+        ];
+
+        for (range, part_1, part_2) in cases {
+            assert_eq!(
+                invalid_in_range(range.clone(), Policy::Exactly(2)).sum,
+                part_1
+            );
+            assert_eq!(invalid_in_range(range, Policy::AtLeast(2)).sum, part_2);
+        }
+    }
+
+    #[test]
+    fn test_invalid_in_range_matches_brute_force() {
+        let policies = [
+            Policy::Exactly(2),
+            Policy::Exactly(3),
+            Policy::AtLeast(2),
+            Policy::AtLeast(3),
+            Policy::Custom(|repeats| repeats % 2 == 1),
+        ];
+        for policy in policies {
+            // Every ID into six digits, then windows around the seven and
+            // eight digit boundaries and around runs of repeated blocks.
+            let ranges = [
+                1..=120_000,
+                123_000..=124_000,
+                999_000..=1_001_000,
+                1_111_000..=1_112_000,
+                9_999_000..=10_001_000,
+                5..=5,
+                111_111..=111_111,
+            ];
+            for range in ranges {
+                let invalid: Vec<u64> = range
+                    .clone()
+                    .filter(|id| !is_valid_id(&id.to_string(), policy))
+                    .collect();
+
+                let tally = invalid_in_range(range, policy);
+
+                assert_eq!(tally.count, invalid.len() as u128, "{:?}", policy);
+                assert_eq!(tally.sum, invalid.iter().map(|&id| id as u128).sum());
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_in_range_spanning_u64() {
+        // 1844674407 repeated is the largest invalid ID that fits.
+        let largest = 18_446_744_071_844_674_407;
+
+        assert_eq!(
+            invalid_in_range(largest..=u64::MAX, Policy::AtLeast(2)),
+            Tally {
+                count: 1,
+                sum: largest as u128
+            }
+        );
+        // Periods 10 of 20 digits and 9 of 18 digits alone give 1.7 billion.
+        assert!(invalid_in_range(0..=u64::MAX, Policy::AtLeast(2)).count > 1_700_000_000);
+    }
+
+    #[test]
+    fn test_day_2_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let id_ranges = parse_all(parse, input)?;
+
+        let result = solve(&id_ranges, Policy::Exactly(2));
+        let expected = 1227775554;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_2_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let id_ranges = parse_all(parse, input)?;
+
+        let result = solve(&id_ranges, Policy::AtLeast(2));
+        let expected = 4174379265;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_2_custom_policy() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let id_ranges = parse_all(parse, input)?;
+
+        // Any even repeat count means the ID is also some block twice.
+        let result = solve(&id_ranges, Policy::Custom(|repeats| repeats % 2 == 0));
+        let expected = 1227775554;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,166 @@
+use nom::Parser;
+use nom::{IResult, character::complete::digit1};
+
+use aoc_core::parse::{lines_of, parse_all};
+use aoc_core::{Answer, Day, Result, Solution};
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct BatteryBank {
+    digits: Vec<u8>,
+}
+
+fn digits_to_u64(digits: Vec<u8>) -> u64 {
+    digits.iter().fold(0u64, |acc, &d| acc * 10 + d as u64)
+}
+
+fn battery_bank(input: &str) -> IResult<&str, BatteryBank> {
+    digit1
+        .map(|s: &str| s.bytes().map(|b| b - b'0').collect())
+        .map(|digits| BatteryBank { digits })
+        .parse(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<BatteryBank>> {
+    lines_of(battery_bank).parse(input)
+}
+
+fn keep_largest_digits(digits: &[u8], keep: usize) -> Vec<u8> {
+    if digits.len() <= keep {
+        return digits.to_vec();
+    }
+
+    let mut remove = digits.len() - keep;
+    let mut stack: Vec<u8> = Vec::with_capacity(digits.len());
+
+    for &d in digits {
+        while remove > 0 && stack.last().is_some_and(|&last| last < d) {
+            stack.pop();
+            remove -= 1;
+        }
+        stack.push(d);
+    }
+
+    stack.truncate(keep);
+    stack
+}
+
+fn find_largest_joltage(keep: usize, battery_bank: &BatteryBank) -> u64 {
+    let kept = keep_largest_digits(&battery_bank.digits, keep);
+    digits_to_u64(kept)
+}
+
+fn solve(parsed: &[BatteryBank], keep: usize) -> u64 {
+    parsed
+        .iter()
+        .map(|bank| find_largest_joltage(keep, bank))
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Vec<BatteryBank>;
+
+    fn parse(input: &str) -> Result<Vec<BatteryBank>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(banks: &Vec<BatteryBank>) -> Result<Answer> {
+        Ok(solve(banks, 2).into())
+    }
+
+    fn part2(banks: &Vec<BatteryBank>) -> Result<Answer> {
+        Ok(solve(banks, 12).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day03>(2025, 3, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected: Vec<BatteryBank> = vec![
+            BatteryBank {
+                digits: vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+            },
+            BatteryBank {
+                digits: vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+            },
+            BatteryBank {
+                digits: vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+            },
+            BatteryBank {
+                digits: vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+            },
+        ];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_largest_joltage() {
+        let cases = [
+            (
+                vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                987654321111,
+            ),
+            (
+                vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                811111111119,
+            ),
+            (
+                vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                434234234278,
+            ),
+            (
+                vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+                888911112111,
+            ),
+        ];
+
+        for (digits, expected) in cases {
+            let battery_bank = BatteryBank {
+                digits: digits.clone(),
+            };
+            let result = find_largest_joltage(12, &battery_bank);
+            assert_eq!(result, expected, "bank: {:?}", digits)
+        }
+    }
+
+    #[test]
+    fn test_day_3_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items, 2);
+        let expected = 357;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_3_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items, 12);
+        let expected = 3121910778619;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,158 @@
+use nom::IResult;
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_grid::{Grid, parse_grid};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Cell {
+    Dot,
+    At,
+}
+
+fn cell(c: char) -> Option<Cell> {
+    match c {
+        '.' => Some(Cell::Dot),
+        '@' => Some(Cell::At),
+        _ => None,
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Grid<Cell>> {
+    parse_grid(cell)(input)
+}
+
+fn remove_accessible(grid: &Grid<Cell>) -> (u32, Grid<Cell>) {
+    let mut accessible = 0u32;
+    let mut next_grid = grid.clone();
+
+    for (pos, &cell) in grid.iter() {
+        if cell == Cell::Dot {
+            continue;
+        }
+
+        let adjacent_ats = grid
+            .neighbors8(pos)
+            .filter(|&(_, &c)| c == Cell::At)
+            .count();
+
+        if adjacent_ats < 4 {
+            next_grid[pos] = Cell::Dot;
+            accessible += 1;
+        }
+    }
+
+    (accessible, next_grid)
+}
+
+fn solve(mut grid: Grid<Cell>) -> u32 {
+    let mut removed = 0u32;
+
+    loop {
+        let (count_removed, next_grid) = remove_accessible(&grid);
+        removed += count_removed;
+
+        if count_removed == 0 {
+            break removed;
+        }
+        grid = next_grid;
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed<'a> = Grid<Cell>;
+
+    fn parse(input: &str) -> Result<Grid<Cell>> {
+        parse_all(parse, input)
+    }
+
+    fn part2(grid: &Grid<Cell>) -> Result<Answer> {
+        Ok(solve(grid.clone()).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day04>(2025, 4, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = "..@
+@@@
+@.@";
+
+        let expected = Grid::new(
+            3,
+            vec![
+                Cell::Dot,
+                Cell::Dot,
+                Cell::At,
+                Cell::At,
+                Cell::At,
+                Cell::At,
+                Cell::At,
+                Cell::Dot,
+                Cell::At,
+            ],
+        );
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_accesible() -> Result<()> {
+        let input = "..@
+@@@
+@.@";
+
+        let grid = parse_all(parse, input)?;
+
+        let result = remove_accessible(&grid);
+
+        let expected_grid_input = "...
+.@.
+...";
+        let next_grid = parse_all(parse, expected_grid_input)?;
+
+        let expected = (5, next_grid);
+
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    //  0123456789
+    //0 ..xx.xx@x.
+    //1 x@@.@.@.@@
+    //2 @@@@@.x.@@
+    //3 @.@@@@..@.
+    //4 x@.@@@@.@x
+    //5 .@@@@@@@.@
+    //6 .@.@.@.@@@
+    //7 x.@@@.@@@@
+    //8 .@@@@@@@@.
+    //9 x.x.@@@.x.
+
+    #[test]
+    fn test_day_4_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        let result = solve(items);
+        let expected = 43;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
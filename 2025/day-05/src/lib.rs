@@ -0,0 +1,86 @@
+use nom::Parser;
+use nom::{IResult, combinator::map, sequence::separated_pair};
+
+use aoc_core::parse::{blank_line, lines_of, parse_all, range, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_interval::IntervalSet;
+
+/// The fresh ingredient ID ranges, merged, and the IDs on hand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inventory {
+    fresh: IntervalSet,
+    ids: Vec<u64>,
+}
+
+fn parse(input: &str) -> IResult<&str, Inventory> {
+    map(
+        separated_pair(lines_of(range(unsigned)), blank_line, lines_of(unsigned)),
+        |(ranges, ids)| Inventory {
+            fresh: ranges.into_iter().collect(),
+            ids,
+        },
+    )
+    .parse(input)
+}
+
+fn count_fresh_on_hand(inventory: &Inventory) -> usize {
+    inventory
+        .ids
+        .iter()
+        .filter(|&&id| inventory.fresh.contains(id))
+        .count()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = Inventory;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        parse_all(parse, input)
+    }
+
+    fn part1(inventory: &Inventory) -> Result<Answer> {
+        Ok(count_fresh_on_hand(inventory).into())
+    }
+
+    fn part2(inventory: &Inventory) -> Result<Answer> {
+        Ok(inventory.fresh.len().into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day05>(2025, 5, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_merges_ranges() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let inventory = parse_all(parse, input)?;
+
+        assert_eq!(
+            inventory.fresh.iter().collect::<Vec<_>>(),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(inventory.ids, vec![1, 5, 8, 11, 17, 32]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_5() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let inventory = parse_all(parse, input)?;
+
+        assert_eq!(count_fresh_on_hand(&inventory), 3);
+        assert_eq!(inventory.fresh.len(), 14);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,234 @@
+use nom::Parser;
+use nom::{
+    IResult,
+    character::complete::{line_ending, not_line_ending},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+};
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_geom::Point2;
+use aoc_grid::Grid;
+use std::iter;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl TryFrom<char> for Op {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '+' => Ok(Op::Add),
+            '*' => Ok(Op::Mul),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One problem on the worksheet: its operator and the block of characters
+/// above it, with the alignment spaces kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Problem {
+    op: Op,
+    block: Grid<char>,
+}
+
+impl Problem {
+    fn row_numbers(&self) -> Vec<u64> {
+        self.block
+            .rows()
+            .filter_map(|row| read_number(row.iter()))
+            .collect()
+    }
+
+    fn column_numbers(&self) -> Vec<u64> {
+        self.block.columns().filter_map(read_number).collect()
+    }
+}
+
+type Parsed = Vec<Problem>;
+
+/// The digits among `chars` read as one number, or `None` if there are none.
+fn read_number<'a>(chars: impl Iterator<Item = &'a char>) -> Option<u64> {
+    chars
+        .filter_map(|c| c.to_digit(10))
+        .map(u64::from)
+        .reduce(|n, digit| n * 10 + digit)
+}
+
+/// `line` from its `column`th character on, for pointing errors at it.
+fn from_column(line: &str, column: usize) -> &str {
+    line.char_indices()
+        .nth(column)
+        .map_or(&line[line.len()..], |(idx, _)| &line[idx..])
+}
+
+fn parse(input: &str) -> IResult<&str, Parsed> {
+    let (rest, lines) = separated_list1(line_ending, not_line_ending).parse(input)?;
+    let lines: Vec<&str> = lines.into_iter().filter(|line| !line.is_empty()).collect();
+    let Some((ops_line, number_lines)) = lines.split_last() else {
+        return Err(nom::Err::Failure(Error::new(rest, ErrorKind::Eof)));
+    };
+
+    for line in number_lines {
+        if let Some((idx, _)) = line
+            .char_indices()
+            .find(|&(_, c)| c != ' ' && !c.is_ascii_digit())
+        {
+            return Err(nom::Err::Failure(Error::new(
+                &line[idx..],
+                ErrorKind::Digit,
+            )));
+        }
+    }
+
+    // Short lines read as if padded with spaces.
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let cells = lines
+        .iter()
+        .flat_map(|line| line.chars().chain(iter::repeat(' ')).take(width))
+        .collect();
+    let grid = Grid::new(width, cells);
+    let height = number_lines.len();
+
+    let blank: Vec<bool> = grid
+        .columns()
+        .map(|mut column| column.all(|&c| c == ' '))
+        .collect();
+
+    let mut parsed: Parsed = Vec::new();
+    let mut start = 0;
+    while start < width {
+        if blank[start] {
+            start += 1;
+            continue;
+        }
+        let end = (start..width).find(|&x| blank[x]).unwrap_or(width);
+
+        // Each problem's operator sits under its leftmost column.
+        let Ok(op) = Op::try_from(grid[Point2::new(start as i64, height as i64)]) else {
+            let at = from_column(ops_line, start);
+            return Err(nom::Err::Failure(Error::new(at, ErrorKind::OneOf)));
+        };
+
+        let block = (0..height)
+            .flat_map(|y| grid.row(y)[start..end].iter().copied())
+            .collect();
+
+        parsed.push(Problem {
+            op,
+            block: Grid::new(end - start, block),
+        });
+        start = end;
+    }
+
+    Ok((rest, parsed))
+}
+
+fn solve(parsed: &Parsed, numbers: fn(&Problem) -> Vec<u64>) -> u64 {
+    parsed
+        .iter()
+        .map(|problem| match problem.op {
+            Op::Mul => numbers(problem).iter().product::<u64>(),
+            Op::Add => numbers(problem).iter().sum::<u64>(),
+        })
+        .sum::<u64>()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Result<Parsed> {
+        parse_all(parse, input)
+    }
+
+    fn part1(problems: &Parsed) -> Result<Answer> {
+        Ok(solve(problems, Problem::row_numbers).into())
+    }
+
+    fn part2(problems: &Parsed) -> Result<Answer> {
+        Ok(solve(problems, Problem::column_numbers).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day06>(2025, 6, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem(op: Op, rows: &[&str]) -> Problem {
+        Problem {
+            op,
+            block: Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect())
+                .expect("rows should be the same length"),
+        }
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected: Parsed = vec![
+            problem(Op::Mul, &["123", " 45", "  6"]),
+            problem(Op::Add, &["328", "64 ", "98 "]),
+            problem(Op::Mul, &[" 51", "387", "215"]),
+            problem(Op::Add, &["64 ", "23 ", "314"]),
+        ];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_6_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items, Problem::row_numbers);
+        let expected = 4277556;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_6_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items, Problem::column_numbers);
+        let expected = 3263827;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_points_at_unknown_operator() {
+        let input = "12 34\n4  56\n*  -\n";
+
+        let error = parse_all(parse, input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 3, column 4: expected OneOf"
+        );
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
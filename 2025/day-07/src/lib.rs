@@ -0,0 +1,145 @@
+use nom::{
+    IResult,
+    error::{Error, ErrorKind},
+};
+
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_grid::{Grid, parse_grid};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Cell {
+    Empty,
+    Start,
+    Split,
+}
+
+type Node = usize;
+
+#[derive(Debug, PartialEq)]
+pub struct Manifold {
+    start_node: Node,
+    splits: Grid<bool>,
+}
+
+fn cell(c: char) -> Option<Cell> {
+    match c {
+        '.' => Some(Cell::Empty),
+        'S' => Some(Cell::Start),
+        '^' => Some(Cell::Split),
+        _ => None,
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Manifold> {
+    let (rest, grid) = parse_grid(cell)(input)?;
+
+    let Some(start_node) = grid.row(0).iter().position(|&cell| cell == Cell::Start) else {
+        let first_line = input.trim_start();
+        return Err(nom::Err::Failure(Error::new(first_line, ErrorKind::Char)));
+    };
+
+    let out = Manifold {
+        start_node,
+        splits: grid.map(|&cell| cell == Cell::Split),
+    };
+
+    Ok((rest, out))
+}
+
+fn solve(manifold: &Manifold) -> u64 {
+    let width = manifold.splits.width();
+    let mut curr = vec![0u64; width];
+    curr[manifold.start_node] = 1;
+
+    for splits in manifold.splits.rows().skip(1) {
+        let mut next = vec![0u64; width];
+
+        for col_idx in 0..width {
+            let path_count = curr[col_idx];
+
+            if splits[col_idx] {
+                // Beams split off the edge of the manifold are lost.
+                let sides = [col_idx.checked_sub(1), Some(col_idx + 1)];
+                for side in sides.into_iter().flatten() {
+                    if let Some(count) = next.get_mut(side) {
+                        *count += path_count;
+                    }
+                }
+            } else {
+                next[col_idx] += path_count;
+            }
+        }
+
+        curr = next;
+    }
+
+    curr.iter().sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Manifold;
+
+    fn parse(input: &str) -> Result<Manifold> {
+        parse_all(parse, input)
+    }
+
+    fn part2(manifold: &Manifold) -> Result<Answer> {
+        Ok(solve(manifold).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day07>(2025, 7, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected_start_node = 7;
+        let expected_height = 16;
+        let expected_width = 15;
+        let expected_splits = vec![
+            false, false, false, false, false, false, false, false, false, false, false, false,
+            false, false, false,
+        ];
+
+        let manifold = parse_all(parse, input)?;
+
+        assert_eq!(manifold.splits.width(), expected_width);
+        assert_eq!(manifold.splits.height(), expected_height);
+        assert_eq!(manifold.start_node, expected_start_node);
+        assert_eq!(manifold.splits.row(0), expected_splits);
+        Ok(())
+    }
+
+    #[test]
+    fn test_beams_split_off_the_edge_are_lost() -> Result<()> {
+        let manifold = parse_all(parse, "S.\n^.\n.^\n")?;
+
+        assert_eq!(solve(&manifold), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_7_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let manifold = parse_all(parse, input)?;
+
+        let result = solve(&manifold);
+        let expected = 40;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
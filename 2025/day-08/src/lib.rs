@@ -0,0 +1,201 @@
+use std::fmt;
+
+use nom::Parser;
+use nom::{IResult, bytes::complete::tag, combinator::map};
+
+use aoc_core::parse::{lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_dsu::Dsu;
+use aoc_geom::{KdTree, Point3, euclidean_mst};
+
+pub type Junction = Point3;
+
+fn junction(input: &str) -> IResult<&str, Junction> {
+    map(
+        (unsigned, tag(","), unsigned, tag(","), unsigned),
+        |(x, _, y, _, z)| Junction::new(x, y, z),
+    )
+    .parse(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Junction>> {
+    lines_of(junction).parse(input)
+}
+
+/// What to work out from connecting junctions, closest pairs first.
+#[derive(Clone, Copy, Debug)]
+pub enum Mode {
+    /// Make the `connections` closest connections, then multiply the sizes
+    /// of the `largest` biggest circuits.
+    Circuits { connections: usize, largest: usize },
+    /// Keep connecting until there is one circuit, then multiply the x
+    /// coordinates of the last two junctions joined.
+    LastConnection,
+}
+
+/// A group of connected junctions, shown as they appear in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Circuit(pub Vec<Junction>);
+
+impl fmt::Display for Circuit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let junctions: Vec<String> = self
+            .0
+            .iter()
+            .map(|j| format!("{},{},{}", j.x, j.y, j.z))
+            .collect();
+        write!(f, "{}", junctions.join(" "))
+    }
+}
+
+/// The circuits left after connecting the `connections` closest pairs,
+/// each listing its junctions in input order. Pairs already in the same
+/// circuit still use up a connection.
+pub fn circuits(junctions: &[Junction], connections: usize) -> Vec<Circuit> {
+    let mut dsu = Dsu::new(junctions.len());
+    for (i, j, _) in KdTree::new(junctions).closest_pairs().take(connections) {
+        dsu.union(i, j);
+    }
+    dsu.groups()
+        .into_iter()
+        .map(|group| Circuit(group.into_iter().map(|i| junctions[i]).collect()))
+        .collect()
+}
+
+/// For [`Mode::LastConnection`], the last connection is the longest edge
+/// of a minimum spanning tree, so there is no need to generate and sort
+/// every pair. Edges of the same length are taken in index order, as the
+/// closest pairs come, so a tie for longest goes to the one made last.
+fn solve(junctions: &[Junction], mode: Mode) -> i64 {
+    match mode {
+        Mode::Circuits {
+            connections,
+            largest,
+        } => {
+            let mut sizes: Vec<i64> = circuits(junctions, connections)
+                .iter()
+                .map(|circuit| circuit.0.len() as i64)
+                .collect();
+            sizes.sort_unstable_by(|a, b| b.cmp(a));
+            sizes.iter().take(largest).product()
+        }
+        Mode::LastConnection => euclidean_mst(junctions)
+            .into_iter()
+            .max_by_key(|&(i, j, d)| (d, i, j))
+            .map_or(0, |(i, j, _)| junctions[i].x * junctions[j].x),
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = Vec<Junction>;
+
+    fn parse(input: &str) -> Result<Vec<Junction>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(junctions: &Vec<Junction>) -> Result<Answer> {
+        let mode = Mode::Circuits {
+            connections: 1000,
+            largest: 3,
+        };
+        Ok(solve(junctions, mode).into())
+    }
+
+    fn part2(junctions: &Vec<Junction>) -> Result<Answer> {
+        Ok(solve(junctions, Mode::LastConnection).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day08>(2025, 8, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = "162,817,812
+57,618,57";
+
+        let expected: Vec<Junction> = vec![
+            Junction {
+                x: 162,
+                y: 817,
+                z: 812,
+            },
+            Junction {
+                x: 57,
+                y: 618,
+                z: 57,
+            },
+        ];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_8_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        // The example makes only ten connections rather than a thousand.
+        let mode = Mode::Circuits {
+            connections: 10,
+            largest: 3,
+        };
+        let result = solve(&items, mode);
+        let expected = 40;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_circuits() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        // The closest pair is 162,817,812 and 425,690,689, then the first
+        // of those and 431,825,988.
+        let circuits = circuits(&items, 2);
+        assert_eq!(circuits.len(), items.len() - 2);
+        assert_eq!(
+            circuits[0].to_string(),
+            "162,817,812 431,825,988 425,690,689"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_8_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items, Mode::LastConnection);
+        let expected = 25272;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_connection_breaks_ties_by_index() -> Result<()> {
+        // Three of the connections are 2 long. Connecting closest pairs
+        // first, the one from 3,0,0 to 1,0,0 is made last.
+        let items = parse_all(parse, "6,0,0\n5,0,0\n3,0,0\n1,0,0\n6,2,0")?;
+
+        assert_eq!(solve(&items, Mode::LastConnection), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,260 @@
+use nom::Parser;
+use nom::{IResult, bytes::complete::tag, combinator::map, sequence::separated_pair};
+
+use aoc_core::parse::{lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+use aoc_geom::Point2;
+
+pub type Position = Point2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Edge {
+    a: Position,
+    b: Position,
+}
+
+fn position(input: &str) -> IResult<&str, Position> {
+    map(separated_pair(unsigned, tag(","), unsigned), |(x, y)| {
+        Position::new(x, y)
+    })
+    .parse(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Position>> {
+    lines_of(position).parse(input)
+}
+
+fn area(a: Position, b: Position) -> i64 {
+    ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
+}
+
+fn edges(points: &[Position]) -> Vec<Edge> {
+    let first = points.first().copied();
+    points
+        .iter()
+        .copied()
+        .zip(points.iter().copied().skip(1).chain(first))
+        .map(move |(a, b)| Edge { a, b })
+        .collect::<Vec<Edge>>()
+}
+
+/// The floor on a compressed grid: each x or y used by a red tile gets a
+/// line of cells, and each run of values between two of those gets a single
+/// cell. No edge of the loop crosses the inside of a cell, so a cell is
+/// either wholly inside the loop, wholly outside it, or on it.
+struct Floor {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    /// `outside[r][c]`: how many tiles in the cells above and left of
+    /// `(r, c)` are neither red nor green, as a 2D prefix sum.
+    outside: Vec<Vec<i64>>,
+}
+
+/// How many values cell `i` of a compressed axis over `values` spans: one
+/// for a line, at even `i`, and the width of the gap at odd `i`.
+fn tiles_in_cell(values: &[i64], i: usize) -> i64 {
+    if i.is_multiple_of(2) {
+        1
+    } else {
+        values[i / 2 + 1] - values[i / 2] - 1
+    }
+}
+
+/// A gap cell next to cell `i` of an axis `len` cells long, if there is one.
+fn gap_beside(i: usize, len: usize) -> Option<usize> {
+    match i {
+        _ if i % 2 == 1 => Some(i),
+        _ if i + 1 < len => Some(i + 1),
+        _ => i.checked_sub(1),
+    }
+}
+
+impl Floor {
+    /// `red` must not be empty.
+    fn new(red: &[Position]) -> Self {
+        let axis = |coord: fn(&Position) -> i64| {
+            let mut values: Vec<i64> = red.iter().map(coord).collect();
+            values.sort_unstable();
+            values.dedup();
+            values
+        };
+        let (xs, ys) = (axis(|p| p.x), axis(|p| p.y));
+        let (width, height) = (2 * xs.len() - 1, 2 * ys.len() - 1);
+
+        // Mark the cells the loop runs along. A vertical edge also flips
+        // whether the gap cells to its right on the rows it crosses are
+        // inside.
+        let mut on_loop = vec![vec![false; width]; height];
+        let mut flips = vec![vec![false; width]; height];
+        for e in edges(red) {
+            let (c1, r1) = Self::cell_in(&xs, &ys, e.a);
+            let (c2, r2) = Self::cell_in(&xs, &ys, e.b);
+            let (c1, c2) = (c1.min(c2), c1.max(c2));
+            let (r1, r2) = (r1.min(r2), r1.max(r2));
+            for row in &mut on_loop[r1..=r2] {
+                row[c1..=c2].fill(true);
+            }
+            if c1 == c2 && c1 + 1 < width {
+                for row in &mut flips[r1 + 1..r2] {
+                    row[c1 + 1] ^= true;
+                }
+            }
+        }
+        let inside: Vec<Vec<bool>> = flips
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .scan(false, |inside, &flip| {
+                        *inside ^= flip;
+                        Some(*inside)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // A cell off the loop has no edge near it, so it is inside exactly
+        // when a neighbouring gap-by-gap cell is.
+        let mut outside = vec![vec![0; width + 1]; height + 1];
+        for r in 0..height {
+            for c in 0..width {
+                let covered = on_loop[r][c]
+                    || gap_beside(r, height)
+                        .zip(gap_beside(c, width))
+                        .is_some_and(|(r, c)| inside[r][c]);
+                let missing = if covered {
+                    0
+                } else {
+                    tiles_in_cell(&ys, r) * tiles_in_cell(&xs, c)
+                };
+                outside[r + 1][c + 1] =
+                    missing + outside[r][c + 1] + outside[r + 1][c] - outside[r][c];
+            }
+        }
+
+        Floor { xs, ys, outside }
+    }
+
+    fn cell_in(xs: &[i64], ys: &[i64], p: Position) -> (usize, usize) {
+        let line = |values: &[i64], v| 2 * values.binary_search(&v).expect("a red tile coordinate");
+        (line(xs, p.x), line(ys, p.y))
+    }
+
+    /// Whether every tile in the rectangle with corners at red tiles `a`
+    /// and `b` is red or green.
+    fn is_covered(&self, a: Position, b: Position) -> bool {
+        let (c1, r1) = Self::cell_in(&self.xs, &self.ys, a);
+        let (c2, r2) = Self::cell_in(&self.xs, &self.ys, b);
+        let (c1, c2) = (c1.min(c2), c1.max(c2) + 1);
+        let (r1, r2) = (r1.min(r2), r1.max(r2) + 1);
+        let o = &self.outside;
+        o[r2][c2] - o[r1][c2] - o[r2][c1] + o[r1][c1] == 0
+    }
+}
+
+/// The largest rectangle with red tiles at two opposite corners that only
+/// covers red and green tiles.
+///
+/// With the floor compressed to `O(n)` by `O(n)` cells and a prefix sum of
+/// uncovered tiles, each of the `O(n²)` candidates is checked in constant
+/// time.
+fn solve(points: &[Position]) -> i64 {
+    if points.is_empty() {
+        return 0;
+    }
+    let floor = Floor::new(points);
+
+    let mut best = 0;
+    for (i, &a) in points.iter().enumerate() {
+        for &b in &points[i + 1..] {
+            let candidate = area(a, b);
+            if candidate > best && floor.is_covered(a, b) {
+                best = candidate;
+            }
+        }
+    }
+    best
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Position>;
+
+    fn parse(input: &str) -> Result<Vec<Position>> {
+        parse_all(parse, input)
+    }
+
+    fn part2(tiles: &Vec<Position>) -> Result<Answer> {
+        Ok(solve(tiles).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day09>(2025, 9, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected: Vec<Position> = vec![
+            Position { x: 7, y: 1 },
+            Position { x: 11, y: 1 },
+            Position { x: 11, y: 7 },
+            Position { x: 9, y: 7 },
+            Position { x: 9, y: 5 },
+            Position { x: 2, y: 5 },
+            Position { x: 2, y: 3 },
+            Position { x: 7, y: 3 },
+        ];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concave_corners() {
+        // An L whose two arms have corners at (10, 2) and (2, 10). The
+        // rectangle between those is mostly outside, though no edge passes
+        // through its inside.
+        let l_shape = [(0, 0), (10, 0), (10, 2), (2, 2), (2, 10), (0, 10)].map(Position::from);
+
+        assert_eq!(solve(&l_shape), 33);
+
+        // Two arms touching along x = 5 and x = 6 with no tiles between
+        // them: the rectangle across both is all red or green.
+        let touching = [
+            (0, 0),
+            (11, 0),
+            (11, 4),
+            (6, 4),
+            (6, 2),
+            (5, 2),
+            (5, 4),
+            (0, 4),
+        ]
+        .map(Position::from);
+
+        assert_eq!(solve(&touching), 12 * 5);
+    }
+
+    #[test]
+    fn test_day_9_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let tiles = parse_all(parse, input)?;
+
+        let result = solve(&tiles);
+        let expected = 24;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
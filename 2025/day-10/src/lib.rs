@@ -0,0 +1,256 @@
+use aoc_core::parse::{comma_list, lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Error, Result, Solution};
+use aoc_linalg::{BitVector, minimize_sum, solve_gf2};
+use nom::Parser;
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::value,
+    error::{Error as NomError, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{delimited, terminated},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Light {
+    On,
+    Off,
+}
+
+type LightDiagram = Vec<Light>;
+type ButtonWiring = Vec<i64>;
+type Joltage = Vec<i64>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Machine {
+    button_wirings: Vec<ButtonWiring>,
+    light_target: LightDiagram,
+    joltage_target: Joltage,
+}
+
+fn light(input: &str) -> IResult<&str, Light> {
+    alt((value(Light::On, tag("#")), value(Light::Off, tag(".")))).parse(input)
+}
+
+fn light_diagram(input: &str) -> IResult<&str, LightDiagram> {
+    delimited(tag("["), many1(light), tag("]")).parse(input)
+}
+
+fn buttons(input: &str) -> IResult<&str, Vec<Vec<usize>>> {
+    separated_list1(
+        tag(" "),
+        delimited(tag("("), comma_list(unsigned), tag(")")),
+    )
+    .parse(input)
+}
+
+fn joltage(input: &str) -> IResult<&str, Joltage> {
+    delimited(tag("{"), comma_list(unsigned), tag("}")).parse(input)
+}
+
+fn build_button_matrix(values: Vec<Vec<usize>>, n: usize) -> Vec<ButtonWiring> {
+    (0..n)
+        .map(|i| {
+            values
+                .iter()
+                .map(|button| button.contains(&i).into())
+                .collect()
+        })
+        .collect()
+}
+
+/// One machine per line. The diagram and the joltages must cover the same
+/// lights, and every button must be wired to one of them.
+fn machine(input: &str) -> IResult<&str, Machine> {
+    let (buttons_at, light_target) = terminated(light_diagram, tag(" ")).parse(input)?;
+    let (joltage_at, buttons) = terminated(buttons, tag(" ")).parse(buttons_at)?;
+    let (input, joltage_target) = joltage.parse(joltage_at)?;
+
+    let n = joltage_target.len();
+    let invalid = |at| nom::Err::Failure(NomError::new(at, ErrorKind::Verify));
+    if light_target.len() != n {
+        return Err(invalid(joltage_at));
+    }
+    if buttons.iter().flatten().any(|&light| light >= n) {
+        return Err(invalid(buttons_at));
+    }
+    let button_wirings = build_button_matrix(buttons, n);
+
+    Ok((
+        input,
+        Machine {
+            button_wirings,
+            light_target,
+            joltage_target,
+        },
+    ))
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Machine>> {
+    lines_of(machine).parse(input)
+}
+
+/// The fewest buttons that turn on exactly the lights in the diagram.
+///
+/// A second press of a button undoes the first, so each is pressed at most
+/// once and the lights form a system over GF(2).
+fn light_presses(machine: &Machine) -> Result<Vec<usize>> {
+    let wirings: Vec<BitVector> = machine
+        .button_wirings
+        .iter()
+        .map(|wiring| wiring.iter().map(|&w| w != 0).collect())
+        .collect();
+    let lights: BitVector = machine
+        .light_target
+        .iter()
+        .map(|&light| light == Light::On)
+        .collect();
+
+    let solution = solve_gf2(&wirings, &lights).ok_or_else(|| {
+        Error::no_solution(format!("no buttons light up {:?}", machine.light_target))
+    })?;
+    Ok(solution.min_weight()?.ones().collect())
+}
+
+fn solve_lights(machines: &[Machine]) -> Result<usize> {
+    machines
+        .iter()
+        .map(|machine| Ok(light_presses(machine)?.len()))
+        .sum()
+}
+
+// x1  x2    x3  x4    x5    x6
+// (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+
+// 0x1 + 0x2 + 0x3 + 0x4 + 1x5 + 1x6 = 3
+// 0x1 + 1x2 + 0x3 + 0x4 + 0x5 + 1x6 = 5
+// 0x1 + 0x2 + 1x3 + 1x4 + 1x5 + 0x6 = 4
+// 1x1 + 1x2 + 0x3 + 1x4 + 0x5 + 0x6 = 7
+
+// Ax = b
+fn solve_machine(machine: &Machine) -> Result<usize> {
+    let presses = minimize_sum(&machine.button_wirings, &machine.joltage_target)?;
+
+    Ok(presses.iter().sum::<u64>() as usize)
+}
+
+fn solve(machines: &[Machine]) -> Result<usize> {
+    machines.iter().map(solve_machine).sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(machines: &Vec<Machine>) -> Result<Answer> {
+        Ok(solve_lights(machines)?.into())
+    }
+
+    fn part2(machines: &Vec<Machine>) -> Result<Answer> {
+        Ok(solve(machines)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day10>(2025, 10, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+
+        let expected: Vec<Machine> = vec![Machine {
+            button_wirings: vec![
+                vec![0, 0, 0, 0, 1, 1],
+                vec![0, 1, 0, 0, 0, 1],
+                vec![0, 0, 1, 1, 1, 0],
+                vec![1, 1, 0, 1, 0, 0],
+            ],
+            light_target: vec![Light::Off, Light::On, Light::On, Light::Off],
+            joltage_target: vec![3, 5, 4, 7],
+        }];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_mismatched_lights() {
+        let error = parse_all(parse, "[.#] (0) {3,4,5}").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 1, column 10: expected predicate verification"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unwired_buttons() {
+        let error = parse_all(parse, "[.#] (0) (1,2) {3,4}").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 1, column 6: expected predicate verification"
+        );
+    }
+
+    #[test]
+    fn test_light_presses() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let machines = parse_all(parse, input)?;
+
+        for (machine, expected) in machines.iter().zip([2, 3, 2]) {
+            let presses = light_presses(machine)?;
+            assert_eq!(presses.len(), expected);
+
+            let mut lights = vec![Light::Off; machine.light_target.len()];
+            for (light, wiring) in lights.iter_mut().zip(&machine.button_wirings) {
+                if presses.iter().filter(|&&b| wiring[b] != 0).count() % 2 == 1 {
+                    *light = Light::On;
+                }
+            }
+            assert_eq!(lights, machine.light_target);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_10_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let machines = parse_all(parse, input)?;
+
+        let result = solve_lights(&machines)?;
+        let expected = 7;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_10_part_2() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let machines = parse_all(parse, input)?;
+
+        let result = solve(&machines)?;
+        let expected = 33;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_core::parse::parse_all;
+use aoc_core::{Answer, Day, Error, Result, Solution};
+use aoc_graph::{Graph, NodeId, adjacency_list};
+
+fn device(graph: &Graph, name: &str) -> Result<NodeId> {
+    graph
+        .id(name)
+        .ok_or_else(|| Error::invariant(format!("there is no device called {}", name)))
+}
+
+/// Paths from `svr` to `out` that visit both `dac` and `fft`, in either
+/// order.
+fn solve(graph: &Graph) -> Result<u64> {
+    let [svr, dac, fft, out] = ["svr", "dac", "fft", "out"].map(|name| device(graph, name));
+    let (svr, dac, fft, out) = (svr?, dac?, fft?, out?);
+    let paths = |from, to| graph.count_paths(from, to);
+
+    Ok(paths(svr, dac)? * paths(dac, fft)? * paths(fft, out)?
+        + paths(svr, fft)? * paths(fft, dac)? * paths(dac, out)?)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Graph<'a>;
+
+    fn parse(input: &str) -> Result<Graph<'_>> {
+        parse_all(adjacency_list, input)
+    }
+
+    fn part2(graph: &Graph<'_>) -> Result<Answer> {
+        Ok(solve(graph)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day11>(2025, 11, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = "aaa: you hhh
+you: bbb ccc";
+
+        let graph = parse_all(adjacency_list, input)?;
+
+        let outputs = |name| {
+            let id = graph.id(name).unwrap();
+            graph
+                .successors(id)
+                .iter()
+                .map(|&to| graph.name(to))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(outputs("aaa"), vec!["you", "hhh"]);
+        assert_eq!(outputs("you"), vec!["bbb", "ccc"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_11_part_2() -> Result<()> {
+        let input = include_str!("../examples/part2.txt");
+
+        let graph = parse_all(adjacency_list, input)?;
+
+        let result = solve(&graph)?;
+        let expected = 2;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
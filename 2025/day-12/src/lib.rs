@@ -0,0 +1,170 @@
+use nom::Parser;
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, space1},
+    combinator::{map, map_res, value},
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair},
+};
+
+use aoc_core::parse::{blank_line, blank_line_separated_sections, lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+
+const N_SHAPES: usize = 6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parsed {
+    shapes: [Shape; N_SHAPES],
+    areas: Vec<Area>,
+}
+
+type Cell = usize;
+type Shape = usize;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Area {
+    width: usize,
+    height: usize,
+    shapes: [usize; N_SHAPES],
+}
+
+fn cell(input: &str) -> IResult<&str, Cell> {
+    alt((value(1, tag("#")), value(0, tag(".")))).parse(input)
+}
+
+fn shape(input: &str) -> IResult<&str, Shape> {
+    let (input, grid) = separated_list1(line_ending, many1(cell)).parse(input)?;
+
+    let count = grid.iter().flatten().sum();
+    Ok((input, count))
+}
+
+fn shape_item(input: &str) -> IResult<&str, Shape> {
+    preceded((digit1, tag(":"), line_ending), shape).parse(input)
+}
+
+fn shapes(input: &str) -> IResult<&str, [Shape; N_SHAPES]> {
+    map_res(
+        blank_line_separated_sections(shape_item),
+        <[Shape; N_SHAPES]>::try_from,
+    )
+    .parse(input)
+}
+
+fn area(input: &str) -> IResult<&str, Area> {
+    map(
+        separated_pair(
+            separated_pair(unsigned, tag("x"), unsigned),
+            tag(": "),
+            map_res(
+                separated_list1(space1, unsigned),
+                <[usize; N_SHAPES]>::try_from,
+            ),
+        ),
+        |((width, height), shapes)| Area {
+            width,
+            height,
+            shapes,
+        },
+    )
+    .parse(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Parsed> {
+    map(
+        separated_pair(shapes, blank_line, lines_of(area)),
+        |(shapes, areas)| Parsed { shapes, areas },
+    )
+    .parse(input)
+}
+
+fn is_packable(area: &Area, shapes: &[Shape; N_SHAPES]) -> bool {
+    let total = area.width * area.height;
+    let needed = area
+        .shapes
+        .iter()
+        .zip(shapes.iter())
+        .map(|(&n, &size)| n * size)
+        .sum::<usize>();
+
+    total > needed
+}
+
+fn solve(parsed: &Parsed) -> usize {
+    parsed
+        .areas
+        .iter()
+        .filter(|a| is_packable(a, &parsed.shapes))
+        .count()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Result<Parsed> {
+        parse_all(parse, input)
+    }
+
+    fn part1(parsed: &Parsed) -> Result<Answer> {
+        Ok(solve(parsed).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day12>(2025, 12, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected: Parsed = Parsed {
+            shapes: [7, 7, 7, 7, 7, 7],
+            areas: vec![
+                Area {
+                    width: 4,
+                    height: 4,
+                    shapes: [0, 0, 0, 0, 2, 0],
+                },
+                Area {
+                    width: 12,
+                    height: 5,
+                    shapes: [1, 0, 1, 0, 2, 2],
+                },
+                Area {
+                    width: 12,
+                    height: 5,
+                    shapes: [1, 0, 1, 0, 3, 2],
+                },
+            ],
+        };
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_12_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items);
+        let expected = 3;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,71 @@
+use nom::Parser;
+use nom::{IResult, combinator::map};
+
+use aoc_core::parse::{lines_of, parse_all, unsigned};
+use aoc_core::{Answer, Day, Result, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    value: u64,
+}
+
+fn item(input: &str) -> IResult<&str, Item> {
+    map(unsigned, |value| Item { value }).parse(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Item>> {
+    lines_of(item).parse(input)
+}
+
+fn solve(items: &[Item]) -> u64 {
+    items.iter().map(|item| item.value).sum()
+}
+
+pub struct Day{{dd}};
+
+impl Solution for Day{{dd}} {
+    type Parsed<'a> = Vec<Item>;
+
+    fn parse(input: &str) -> Result<Vec<Item>> {
+        parse_all(parse, input)
+    }
+
+    fn part1(items: &Vec<Item>) -> Result<Answer> {
+        Ok(solve(items).into())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day{{dd}}>({{year}}, {{day}}, env!("CARGO_MANIFEST_DIR"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+
+        let expected = vec![Item { value: 1 }, Item { value: 2 }, Item { value: 3 }];
+
+        let parsed = parse_all(parse, input)?;
+
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_{{day}}_part_1() -> Result<()> {
+        let input = include_str!("../examples/part1.txt");
+        let items = parse_all(parse, input)?;
+
+        let result = solve(&items);
+        let expected = 6;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        aoc_core::check_examples(&DAY)
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// `(year, day, part)`
+pub type Key = (u16, u8, u8);
+
+/// The checked-in ledger of accepted answers.
+///
+/// One answer per line as `year day part answer`; blank lines and lines
+/// starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.splitn(4, char::is_whitespace).collect::<Vec<_>>();
+            let [year, day, part, answer] = fields[..] else {
+                return Err(format!("line {}: expected `year day part answer`", idx + 1));
+            };
+            let key = (
+                year.parse()
+                    .map_err(|e| format!("line {}: year: {}", idx + 1, e))?,
+                day.parse()
+                    .map_err(|e| format!("line {}: day: {}", idx + 1, e))?,
+                part.parse()
+                    .map_err(|e| format!("line {}: part: {}", idx + 1, e))?,
+            );
+
+            if answers.insert(key, answer.trim().to_string()).is_some() {
+                return Err(format!("line {}: duplicate answer for {:?}", idx + 1, key));
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, key: Key) -> Option<&str> {
+        self.answers.get(&key).map(String::as_str)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.answers.keys().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<(), String> {
+        let contents = "\
+# year day part answer
+2024 1 2 22588371
+
+2025 6 1 6100348226985
+";
+        let answers = Answers::parse(contents)?;
+
+        assert_eq!(answers.get((2024, 1, 2)), Some("22588371"));
+        assert_eq!(answers.get((2025, 6, 1)), Some("6100348226985"));
+        assert_eq!(answers.get((2024, 1, 1)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_duplicates() {
+        let contents = "2024 1 2 1\n2024 1 2 2\n";
+
+        assert!(Answers::parse(contents).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_short_lines() {
+        assert!(Answers::parse("2024 1 2\n").is_err());
+    }
+}
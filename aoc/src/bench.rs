@@ -0,0 +1,207 @@
+use aoc_core::{Day, InputLocator, Sampling};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// One timed stage of one day, as written to the JSON report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`
+    pub stage: String,
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub records: Vec<Record>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json + "\n").map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn find(&self, year: u16, day: u8, stage: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .find(|r| r.year == year && r.day == day && r.stage == stage)
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Times every day, reporting days that fail to run on stderr.
+///
+/// Returns the report and whether every day ran.
+pub fn bench(days: &[&Day], locator: &InputLocator, sampling: &Sampling) -> (Report, bool) {
+    let mut report = Report::default();
+    let mut ok = true;
+
+    for day in days {
+        let measurements = locator
+            .read(day)
+            .and_then(|input| (day.bench)(&input, sampling));
+        match measurements {
+            Ok(measurements) => {
+                report
+                    .records
+                    .extend(measurements.into_iter().map(|m| Record {
+                        year: day.year,
+                        day: day.day,
+                        stage: m.stage.to_string(),
+                        runs: m.runs,
+                        min_ns: nanos(m.min),
+                        median_ns: nanos(m.median),
+                        mean_ns: nanos(m.mean),
+                    }));
+            }
+            Err(e) => {
+                eprintln!("[{} Day {}] Error: {}", day.year, day.day, e);
+                ok = false;
+            }
+        }
+    }
+
+    (report, ok)
+}
+
+pub struct Comparison<'a> {
+    pub record: &'a Record,
+    pub baseline: Option<&'a Record>,
+    /// Relative change of the median against the baseline, `0.1` being 10% slower.
+    pub change: Option<f64>,
+    pub regressed: bool,
+}
+
+/// Compares medians with the baseline; slowing down by more than
+/// `threshold` percent counts as a regression.
+pub fn compare<'a>(
+    report: &'a Report,
+    baseline: Option<&'a Report>,
+    threshold: f64,
+) -> Vec<Comparison<'a>> {
+    report
+        .records
+        .iter()
+        .map(|record| {
+            let baseline = baseline.and_then(|b| b.find(record.year, record.day, &record.stage));
+            let change = baseline
+                .filter(|b| b.median_ns > 0)
+                .map(|b| record.median_ns as f64 / b.median_ns as f64 - 1.0);
+            Comparison {
+                record,
+                baseline,
+                change,
+                regressed: change.is_some_and(|c| c * 100.0 > threshold),
+            }
+        })
+        .collect()
+}
+
+fn format_nanos(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+pub fn print_table(comparisons: &[Comparison]) {
+    println!(
+        "{:>4}  {:>3}  {:<5}  {:>6}  {:>10}  {:>10}  Change",
+        "Year", "Day", "Stage", "Runs", "Median", "Baseline"
+    );
+    for Comparison {
+        record,
+        baseline,
+        change,
+        regressed,
+    } in comparisons
+    {
+        let baseline = baseline.map_or("-".to_string(), |b| format_nanos(b.median_ns));
+        let change = match change {
+            Some(change) => format!(
+                "{:+.1}%{}",
+                change * 100.0,
+                if *regressed { "  REGRESSED" } else { "" }
+            ),
+            None => "-".to_string(),
+        };
+        println!(
+            "{:>4}  {:>3}  {:<5}  {:>6}  {:>10}  {:>10}  {}",
+            record.year,
+            record.day,
+            record.stage,
+            record.runs,
+            format_nanos(record.median_ns),
+            baseline,
+            change
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+    if regressions > 0 {
+        println!("\n{} regression(s)", regressions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, stage: &str, median_ns: u64) -> Record {
+        Record {
+            year: 2024,
+            day,
+            stage: stage.to_string(),
+            runs: 10,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_compare_flags_slowdowns_above_threshold() {
+        let baseline = Report {
+            records: vec![record(1, "parse", 1000), record(1, "part1", 1000)],
+        };
+        let report = Report {
+            records: vec![
+                record(1, "parse", 1050),
+                record(1, "part1", 1200),
+                record(1, "part2", 1000),
+            ],
+        };
+
+        let comparisons = compare(&report, Some(&baseline), 10.0);
+
+        let regressed = comparisons.iter().map(|c| c.regressed).collect::<Vec<_>>();
+        assert_eq!(regressed, vec![false, true, false]);
+        assert!(comparisons[2].baseline.is_none());
+    }
+
+    #[test]
+    fn test_report_round_trips_through_json() -> Result<(), String> {
+        let report = Report {
+            records: vec![record(3, "part2", 42)],
+        };
+
+        let json = serde_json::to_string(&report).map_err(|e| e.to_string())?;
+        let parsed: Report = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+        assert_eq!(parsed.records, report.records);
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use aoc_core::Day;
+
+pub static DAYS: &[&Day] = &[
+    &aoc_2024_day_01::DAY,
+    &aoc_2024_day_02::DAY,
+    &aoc_2024_day_03::DAY,
+    &aoc_2024_day_04::DAY,
+    &aoc_2024_day_05::DAY,
+    &aoc_2024_day_06::DAY,
+    &aoc_2024_day_07::DAY,
+    &aoc_2024_day_08::DAY,
+    &aoc_2024_day_09::DAY,
+    &aoc_2024_day_10::DAY,
+    &aoc_2024_day_11::DAY,
+    &aoc_2025_day_01::DAY,
+    &aoc_2025_day_02::DAY,
+    &aoc_2025_day_03::DAY,
+    &aoc_2025_day_04::DAY,
+    &aoc_2025_day_05::DAY,
+    &aoc_2025_day_06::DAY,
+    &aoc_2025_day_07::DAY,
+    &aoc_2025_day_08::DAY,
+    &aoc_2025_day_09::DAY,
+    &aoc_2025_day_10::DAY,
+    &aoc_2025_day_11::DAY,
+    &aoc_2025_day_12::DAY,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .copied()
+        .find(|d| d.year == year && d.day == day)
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().copied().filter(move |d| d.year == year)
+}
+
+/// Every day of `year`, or of every year, optionally narrowed to one `day`.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .copied()
+        .filter(|d| year.is_none_or(|year| year == d.year) && day.is_none_or(|day| day == d.day))
+        .collect()
+}
@@ -0,0 +1,249 @@
+mod answers;
+mod bench;
+mod days;
+mod scaffold;
+mod timing;
+mod verify;
+
+use answers::Answers;
+use aoc_core::{Day, INPUTS_DIR_VAR, InputLocator, Sampling};
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use timing::{Format, Source};
+use verify::Status;
+
+const DEFAULT_ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day of a year when no day is given
+    Run {
+        year: u16,
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead of looking it up (`-` for stdin)
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Run on each day's worked examples and check the recorded answers
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// Directory holding inputs as `<year>/day-NN.txt`
+        #[arg(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+        /// How to print the answers and how long each stage took
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Re-run solutions and check them against the answers ledger
+    Verify {
+        /// Only verify this year
+        year: Option<u16>,
+        /// Only verify this day of the year
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        #[arg(long, default_value = DEFAULT_ANSWERS)]
+        answers: PathBuf,
+        /// Directory holding inputs as `<year>/day-NN.txt`
+        #[arg(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Time parsing and each part of every day; build with --release
+    Bench {
+        /// Only benchmark this year
+        year: Option<u16>,
+        /// Only benchmark this day of the year
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Compare against results previously written with --output
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown of the median, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// How long to keep re-running each stage, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        millis: u64,
+        /// Directory holding inputs as `<year>/day-NN.txt`
+        #[arg(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Create a new day's crate from the year's templates and register it
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            example,
+            inputs_dir,
+            format,
+        } => {
+            let locator = InputLocator { input, inputs_dir };
+
+            let selected: Vec<&Day> = match day {
+                Some(day) => match days::find(year, day) {
+                    Some(d) => vec![d],
+                    None => {
+                        eprintln!("No solution registered for {} day {}", year, day);
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => days::for_year(year).collect(),
+            };
+
+            if selected.is_empty() {
+                eprintln!("No solutions registered for {}", year);
+                return ExitCode::FAILURE;
+            }
+
+            let parts: Vec<u8> = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let source = if example {
+                Source::Example
+            } else {
+                Source::Puzzle(&locator)
+            };
+            let (summary, mut ok) = timing::run(&selected, &parts, &source);
+            match format {
+                Format::Table => timing::print_table(&summary),
+                Format::Json => {
+                    if let Err(e) = timing::print_json(&summary) {
+                        eprintln!("Error: {}", e);
+                        ok = false;
+                    }
+                }
+                Format::Csv => timing::print_csv(&summary),
+            }
+
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+
+        Command::Verify {
+            year,
+            day,
+            answers,
+            inputs_dir,
+        } => {
+            let answers = match Answers::load(&answers) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let locator = InputLocator {
+                input: None,
+                inputs_dir,
+            };
+
+            let selected =
+                |y: u16, d: u8| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d);
+            let days = days::select(year, day);
+
+            let checks = verify::verify(&days, &answers, &locator, selected);
+            verify::print_table(&checks);
+
+            if checks.iter().any(|c| c.status == Status::Fail) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+
+        Command::Bench {
+            year,
+            day,
+            output,
+            baseline,
+            threshold,
+            millis,
+            inputs_dir,
+        } => {
+            let baseline = match baseline.map(|path| bench::Report::load(&path)).transpose() {
+                Ok(baseline) => baseline,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let locator = InputLocator {
+                input: None,
+                inputs_dir,
+            };
+            let sampling = Sampling {
+                target: Duration::from_millis(millis),
+                ..Sampling::default()
+            };
+
+            let (report, mut ok) = bench::bench(&days::select(year, day), &locator, &sampling);
+            let comparisons = bench::compare(&report, baseline.as_ref(), threshold);
+            bench::print_table(&comparisons);
+            ok &= !comparisons.iter().any(|c| c.regressed);
+
+            if let Some(output) = output
+                && let Err(e) = report.save(&output)
+            {
+                eprintln!("Error: {}", e);
+                ok = false;
+            }
+
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+
+        Command::New { year, day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("the runner lives inside the workspace");
+
+            match scaffold::new_day(root, year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!(
+                            "wrote {}",
+                            path.strip_prefix(root).unwrap_or(&path).display()
+                        );
+                    }
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A file of a new day's crate, relative to the crate directory.
+struct Template {
+    path: &'static str,
+    contents: &'static str,
+}
+
+const TEMPLATES_2024: &[Template] = &[
+    Template {
+        path: "Cargo.toml",
+        contents: include_str!("../../2024/cargo_boilerplate.toml"),
+    },
+    Template {
+        path: "src/main.rs",
+        contents: include_str!("../../2024/main_boilerplate.rs"),
+    },
+    Template {
+        path: "src/lib.rs",
+        contents: include_str!("../../2024/lib_boilerplate.rs"),
+    },
+    Template {
+        path: "src/part1.rs",
+        contents: include_str!("../../2024/part_boilerplate.rs"),
+    },
+    Template {
+        path: "examples/part1.txt",
+        contents: include_str!("../../2024/example_boilerplate.txt"),
+    },
+    Template {
+        path: "examples/answers.txt",
+        contents: include_str!("../../2024/answers_boilerplate.txt"),
+    },
+];
+
+const TEMPLATES_2025: &[Template] = &[
+    Template {
+        path: "Cargo.toml",
+        contents: include_str!("../../2025/cargo_boilerplate.toml"),
+    },
+    Template {
+        path: "src/main.rs",
+        contents: include_str!("../../2025/main_boilerplate.rs"),
+    },
+    Template {
+        path: "src/lib.rs",
+        contents: include_str!("../../2025/lib_boilerplate.rs"),
+    },
+    Template {
+        path: "examples/part1.txt",
+        contents: include_str!("../../2025/example_boilerplate.txt"),
+    },
+    Template {
+        path: "examples/answers.txt",
+        contents: include_str!("../../2025/answers_boilerplate.txt"),
+    },
+];
+
+/// 2024 and earlier keep one file per part; later years start from the 2025 layout.
+fn templates(year: u16) -> &'static [Template] {
+    if year <= 2024 {
+        TEMPLATES_2024
+    } else {
+        TEMPLATES_2025
+    }
+}
+
+/// Fills in `{{year}}`, `{{day}}` and the zero-padded `{{dd}}`.
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{dd}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `line` to the run of lines starting with `prefix`, keeping that run sorted.
+///
+/// Returns `Ok(None)` when the line is already there.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> Result<Option<String>, String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .collect::<Vec<_>>();
+
+    if entries.iter().any(|(_, l)| **l == line) {
+        return Ok(None);
+    }
+    let Some(&(last, _)) = entries.last() else {
+        return Err(format!("no lines starting with `{}`", prefix.trim()));
+    };
+    let at = entries
+        .iter()
+        .find(|(_, l)| **l > line)
+        .map_or(last + 1, |&(idx, _)| idx);
+
+    lines.insert(at, line);
+    let mut updated = lines.join("\n");
+    if contents.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(Some(updated))
+}
+
+/// Creates `<year>/day-NN` from the year's templates and registers it with the
+/// workspace and the runner.
+///
+/// Every edit is worked out before anything is written, so a day that is
+/// already registered leaves the tree untouched. Returns the paths written.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join(year.to_string()).join(format!("day-{:02}", day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e));
+    let workspace_toml = root.join("Cargo.toml");
+    let runner_toml = root.join("aoc/Cargo.toml");
+    let days_rs = root.join("aoc/src/days.rs");
+
+    let mut edits = Vec::new();
+
+    let member = format!("    \"{}/day-*\",", year);
+    if let Some(updated) = insert_sorted(&read(&workspace_toml)?, "    \"20", &member)? {
+        edits.push((workspace_toml, updated));
+    }
+
+    let dependency = format!(
+        "aoc-{year}-day-{day:02} = {{ path = \"../{year}/day-{day:02}\" }}",
+        year = year,
+        day = day
+    );
+    match insert_sorted(&read(&runner_toml)?, "aoc-2", &dependency)? {
+        Some(updated) => edits.push((runner_toml, updated)),
+        None => {
+            return Err(format!(
+                "{} already depends on {} day {}",
+                runner_toml.display(),
+                year,
+                day
+            ));
+        }
+    }
+
+    let entry = format!("    &aoc_{}_day_{:02}::DAY,", year, day);
+    match insert_sorted(&read(&days_rs)?, "    &aoc_", &entry)? {
+        Some(updated) => edits.push((days_rs, updated)),
+        None => {
+            return Err(format!(
+                "{} already lists {} day {}",
+                days_rs.display(),
+                year,
+                day
+            ));
+        }
+    }
+
+    let mut written = Vec::new();
+    for template in templates(year) {
+        let path = crate_dir.join(template.path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&path, render(template.contents, year, day))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        written.push(path);
+    }
+    for (path, contents) in edits {
+        fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_fills_in_the_day() {
+        let lib = render(include_str!("../../2025/lib_boilerplate.rs"), 2025, 7);
+
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("Day::new::<Day07>(2025, 7, env!(\"CARGO_MANIFEST_DIR\"))"));
+        assert!(lib.contains("fn test_day_7_part_1()"));
+        assert!(!lib.contains("{{"));
+    }
+
+    #[test]
+    fn test_insert_sorted_keeps_entries_in_order() -> Result<(), String> {
+        let contents = "\
+[dependencies]
+aoc-2024-day-01 = 1
+aoc-2025-day-01 = 1
+aoc-core = 1
+";
+
+        let updated = insert_sorted(contents, "aoc-2", "aoc-2024-day-12 = 1")?;
+        assert_eq!(
+            updated.as_deref(),
+            Some(
+                "\
+[dependencies]
+aoc-2024-day-01 = 1
+aoc-2024-day-12 = 1
+aoc-2025-day-01 = 1
+aoc-core = 1
+"
+            )
+        );
+
+        let updated = insert_sorted(contents, "aoc-2", "aoc-2026-day-01 = 1")?;
+        assert!(
+            updated
+                .is_some_and(|u| u.contains("aoc-2025-day-01 = 1\naoc-2026-day-01 = 1\naoc-core"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_sorted_skips_existing_lines() -> Result<(), String> {
+        let contents = "aoc-2024-day-01 = 1\n";
+
+        assert_eq!(
+            insert_sorted(contents, "aoc-2", "aoc-2024-day-01 = 1")?,
+            None
+        );
+        assert!(insert_sorted(contents, "missing", "missing = 1").is_err());
+        Ok(())
+    }
+}
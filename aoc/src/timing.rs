@@ -0,0 +1,322 @@
+use aoc_core::{Day, Error, InputLocator, PartResult};
+use serde::{Serialize, Serializer};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartTiming {
+    pub part: u8,
+    pub answer: String,
+    #[serde(rename = "solve_ms", serialize_with = "as_millis")]
+    pub solve: Duration,
+}
+
+/// How long each stage of one day took. Reading and parsing happen once per
+/// day, solving once per part.
+#[derive(Debug, Serialize)]
+pub struct DayTiming {
+    pub year: u16,
+    pub day: u8,
+    #[serde(rename = "read_ms", serialize_with = "as_millis")]
+    pub read: Duration,
+    #[serde(rename = "parse_ms", serialize_with = "as_millis")]
+    pub parse: Duration,
+    pub parts: Vec<PartTiming>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Totals {
+    #[serde(rename = "read_ms", serialize_with = "as_millis")]
+    pub read: Duration,
+    #[serde(rename = "parse_ms", serialize_with = "as_millis")]
+    pub parse: Duration,
+    #[serde(rename = "solve_ms", serialize_with = "as_millis")]
+    pub solve: Duration,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    pub days: Vec<DayTiming>,
+    pub total: Totals,
+}
+
+/// Milliseconds, to the nearest microsecond.
+fn millis(duration: &Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+fn as_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(millis(duration))
+}
+
+impl Summary {
+    fn push(&mut self, day: DayTiming) {
+        self.total.read += day.read;
+        self.total.parse += day.parse;
+        self.total.solve += day.parts.iter().map(|p| p.solve).sum::<Duration>();
+        self.days.push(day);
+    }
+}
+
+/// Where each day's input comes from.
+pub enum Source<'a> {
+    Puzzle(&'a InputLocator),
+    /// The worked examples in each day's `examples/`, checked against the
+    /// answers recorded there.
+    Example,
+}
+
+/// Splits `parts` by the file they read; `None` means the located puzzle input.
+fn inputs(day: &Day, parts: &[u8], source: &Source) -> Vec<(Option<PathBuf>, Vec<u8>)> {
+    match source {
+        Source::Puzzle(_) => vec![(None, parts.to_vec())],
+        Source::Example => {
+            let mut inputs: Vec<(Option<PathBuf>, Vec<u8>)> = Vec::new();
+            for &part in parts {
+                let path = Some(day.example_path(part));
+                match inputs.iter_mut().find(|(p, _)| *p == path) {
+                    Some((_, parts)) => parts.push(part),
+                    None => inputs.push((path, vec![part])),
+                }
+            }
+            inputs
+        }
+    }
+}
+
+/// Runs `parts` of every day, timing each stage.
+///
+/// Days and parts that fail, or examples that don't give the recorded answer,
+/// are reported on stderr. Failed days and parts are left out of the summary.
+/// Returns the summary and whether everything ran as expected.
+pub fn run(days: &[&Day], parts: &[u8], source: &Source) -> (Summary, bool) {
+    let mut summary = Summary::default();
+    let mut ok = true;
+
+    for day in days {
+        // Examples without recorded answers are still run, just not checked.
+        let expected = match source {
+            Source::Example => day.example_answers().unwrap_or_default(),
+            Source::Puzzle(_) => Vec::new(),
+        };
+
+        for (path, parts) in inputs(day, parts, source) {
+            let start = Instant::now();
+            let input = match (&path, source) {
+                (Some(path), _) => fs::read_to_string(path).map_err(|e| Error::io(path, e)),
+                (None, Source::Puzzle(locator)) => locator.read(day),
+                (None, Source::Example) => unreachable!("examples always have a path"),
+            };
+            let read_time = start.elapsed();
+
+            let solved = match input.and_then(|input| (day.solve)(&input, &parts)) {
+                Ok(solved) => solved,
+                Err(e) => {
+                    eprintln!("[{} Day {}] Error: {}", day.year, day.day, e);
+                    ok = false;
+                    continue;
+                }
+            };
+
+            let mut timing = DayTiming {
+                year: day.year,
+                day: day.day,
+                read: read_time,
+                parse: solved.parse_time,
+                parts: Vec::new(),
+            };
+            for PartResult {
+                part,
+                answer,
+                elapsed,
+            } in solved.parts
+            {
+                let answer = match answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => {
+                        eprintln!("[{} {}] Error: {}", day.year, day.label(part), e);
+                        ok = false;
+                        continue;
+                    }
+                };
+                if let Some(want) = expected.iter().find(|want| want.part == part)
+                    && want.answer != answer
+                {
+                    let note = if want.known_failure {
+                        "Known failure: expected"
+                    } else {
+                        ok = false;
+                        "Expected"
+                    };
+                    eprintln!(
+                        "[{} {}] {} {}, got {}",
+                        day.year,
+                        day.label(part),
+                        note,
+                        want.answer,
+                        answer
+                    );
+                }
+                timing.parts.push(PartTiming {
+                    part,
+                    answer,
+                    solve: elapsed,
+                });
+            }
+            // An input read only for unsolved parts produced nothing to show.
+            if !timing.parts.is_empty() {
+                summary.push(timing);
+            }
+        }
+    }
+
+    (summary, ok)
+}
+
+pub fn print_table(summary: &Summary) {
+    let width = summary
+        .days
+        .iter()
+        .flat_map(|d| &d.parts)
+        .map(|p| p.answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<width$}  {:>9}  {:>9}  {:>9}",
+        "Year", "Day", "Part", "Answer", "Read ms", "Parse ms", "Solve ms"
+    );
+    for day in &summary.days {
+        // Reading and parsing are shared by the parts, so only the first row shows them.
+        for (idx, part) in day.parts.iter().enumerate() {
+            let (read, parse) = if idx == 0 {
+                (
+                    format!("{:.3}", millis(&day.read)),
+                    format!("{:.3}", millis(&day.parse)),
+                )
+            } else {
+                (String::new(), String::new())
+            };
+            println!(
+                "{:>4}  {:>3}  {:>4}  {:<width$}  {:>9}  {:>9}  {:>9.3}",
+                day.year,
+                day.day,
+                part.part,
+                part.answer,
+                read,
+                parse,
+                millis(&part.solve)
+            );
+        }
+    }
+    println!(
+        "{:<label$}  {:>9.3}  {:>9.3}  {:>9.3}",
+        "Total",
+        millis(&summary.total.read),
+        millis(&summary.total.parse),
+        millis(&summary.total.solve),
+        label = "Year  Day  Part  ".len() + width
+    );
+}
+
+pub fn print_json(summary: &Summary) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(summary).map_err(|e| e.to_string())?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// Quotes a CSV field if it needs it.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One row per part; the day's read and parse times repeat on each of its rows.
+pub fn print_csv(summary: &Summary) {
+    println!("year,day,part,answer,read_ms,parse_ms,solve_ms");
+    for day in &summary.days {
+        for part in &day.parts {
+            println!(
+                "{},{},{},{},{:.3},{:.3},{:.3}",
+                day.year,
+                day.day,
+                part.part,
+                csv_field(&part.answer),
+                millis(&day.read),
+                millis(&day.parse),
+                millis(&part.solve)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u8, solve_ms: &[u64]) -> DayTiming {
+        DayTiming {
+            year: 2025,
+            day,
+            read: Duration::from_millis(1),
+            parse: Duration::from_millis(2),
+            parts: solve_ms
+                .iter()
+                .enumerate()
+                .map(|(idx, &ms)| PartTiming {
+                    part: idx as u8 + 1,
+                    answer: "42".to_string(),
+                    solve: Duration::from_millis(ms),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_totals_count_read_and_parse_once_per_day() {
+        let mut summary = Summary::default();
+        summary.push(day(1, &[3, 4]));
+        summary.push(day(2, &[5]));
+
+        assert_eq!(
+            summary.total,
+            Totals {
+                read: Duration::from_millis(2),
+                parse: Duration::from_millis(4),
+                solve: Duration::from_millis(12),
+            }
+        );
+    }
+
+    #[test]
+    fn test_json_reports_milliseconds() -> Result<(), String> {
+        let mut summary = Summary::default();
+        summary.push(day(1, &[3]));
+
+        let json = serde_json::to_value(&summary).map_err(|e| e.to_string())?;
+
+        assert_eq!(json["days"][0]["parse_ms"], 2.0);
+        assert_eq!(json["days"][0]["parts"][0]["solve_ms"], 3.0);
+        assert_eq!(json["total"]["read_ms"], 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_field_quotes_separators() {
+        assert_eq!(csv_field("6,5,4"), "\"6,5,4\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("1234"), "1234");
+    }
+}
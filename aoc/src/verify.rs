@@ -0,0 +1,134 @@
+use crate::answers::{Answers, Key};
+use aoc_core::{Day, InputLocator, PartResult};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+        f.pad(status)
+    }
+}
+
+pub struct Check {
+    pub key: Key,
+    pub status: Status,
+    pub detail: String,
+}
+
+fn check(key: Key, answer: Result<String, String>, answers: &Answers) -> Check {
+    let (status, detail) = match (answer, answers.get(key)) {
+        (Ok(answer), Some(expected)) if answer == expected => (Status::Pass, answer),
+        (Ok(answer), Some(expected)) => {
+            (Status::Fail, format!("{} (expected {})", answer, expected))
+        }
+        (Ok(answer), None) => (Status::Missing, format!("{} (not in ledger)", answer)),
+        (Err(e), _) => (Status::Fail, format!("error: {}", e)),
+    };
+    Check {
+        key,
+        status,
+        detail,
+    }
+}
+
+/// Runs both parts of every day and compares them with the ledger.
+///
+/// Ledger entries inside the selection that no solution produced are
+/// reported as missing, as are answers that have no ledger entry yet.
+pub fn verify(
+    days: &[&Day],
+    answers: &Answers,
+    locator: &InputLocator,
+    selected: impl Fn(u16, u8) -> bool,
+) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for day in days {
+        let expected_parts = answers
+            .keys()
+            .filter(|&(year, d, _)| year == day.year && d == day.day)
+            .map(|(_, _, part)| part);
+        let results = match locator
+            .read(day)
+            .and_then(|input| (day.solve)(&input, &[1, 2]))
+        {
+            Ok(results) => results,
+            Err(e) => {
+                // Report against every part we expected, or part 1 if we know of none.
+                let parts = expected_parts.collect::<Vec<_>>();
+                let parts = if parts.is_empty() { vec![1] } else { parts };
+                for part in parts {
+                    let key = (day.year, day.day, part);
+                    checks.push(check(key, Err(e.to_string()), answers));
+                }
+                continue;
+            }
+        };
+
+        let solved = results.parts.iter().map(|r| r.part).collect::<Vec<_>>();
+        for PartResult { part, answer, .. } in results.parts {
+            let key = (day.year, day.day, part);
+            let answer = answer.map(|a| a.to_string()).map_err(|e| e.to_string());
+            checks.push(check(key, answer, answers));
+        }
+        for part in expected_parts.filter(|part| !solved.contains(part)) {
+            checks.push(Check {
+                key: (day.year, day.day, part),
+                status: Status::Missing,
+                detail: "no solution".to_string(),
+            });
+        }
+    }
+
+    for key in answers.keys() {
+        let (year, day, _) = key;
+        let registered = days.iter().any(|d| d.year == year && d.day == day);
+        if selected(year, day) && !registered {
+            checks.push(Check {
+                key,
+                status: Status::Missing,
+                detail: "no solution".to_string(),
+            });
+        }
+    }
+
+    checks.sort_by_key(|c| c.key);
+    checks
+}
+
+pub fn print_table(checks: &[Check]) {
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<7}  Answer",
+        "Year", "Day", "Part", "Status"
+    );
+    for Check {
+        key: (year, day, part),
+        status,
+        detail,
+    } in checks
+    {
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<7}  {}",
+            year, day, part, status, detail
+        );
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+}
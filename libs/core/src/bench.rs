@@ -0,0 +1,152 @@
+use crate::{Error, Result, Solution, run_part};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The separately timed pieces of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{}", part)),
+        }
+    }
+}
+
+/// How long to keep re-running a stage.
+///
+/// A stage runs until it has used up `target` and done at least `min_runs`,
+/// or until it reaches `max_runs`. Every stage runs at least once.
+#[derive(Clone, Debug)]
+pub struct Sampling {
+    pub target: Duration,
+    pub min_runs: u32,
+    pub max_runs: u32,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Sampling {
+            target: Duration::from_millis(500),
+            min_runs: 1,
+            max_runs: 10_000,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub stage: Stage,
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+pub type BenchFn = fn(&str, &Sampling) -> Result<Vec<Measurement>>;
+
+fn sample(
+    stage: Stage,
+    sampling: &Sampling,
+    mut run: impl FnMut() -> Result<()>,
+) -> Result<Measurement> {
+    let mut times = Vec::new();
+    let mut total = Duration::ZERO;
+
+    while times.is_empty()
+        || (times.len() < sampling.max_runs as usize
+            && (total < sampling.target || times.len() < sampling.min_runs as usize))
+    {
+        let start = Instant::now();
+        run()?;
+        let elapsed = start.elapsed();
+        times.push(elapsed);
+        total += elapsed;
+    }
+
+    times.sort();
+    Ok(Measurement {
+        stage,
+        runs: times.len() as u32,
+        min: times[0],
+        median: times[times.len() / 2],
+        mean: total / times.len() as u32,
+    })
+}
+
+/// Times parsing and each solved part of `S` on its own.
+///
+/// Parts run against a single parse, so their timings exclude parsing.
+/// Unsolved parts are left out.
+pub fn bench<S: Solution>(input: &str, sampling: &Sampling) -> Result<Vec<Measurement>> {
+    let mut measurements = vec![sample(Stage::Parse, sampling, || {
+        black_box(S::parse(black_box(input))?);
+        Ok(())
+    })?];
+
+    let parsed = S::parse(input)?;
+    for part in [1, 2] {
+        let measurement = sample(Stage::Part(part), sampling, || {
+            black_box(run_part::<S>(black_box(&parsed), part)?);
+            Ok(())
+        });
+        match measurement {
+            Ok(measurement) => measurements.push(measurement),
+            Err(Error::Unsolved) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| Error::invariant("not a number")))
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<u64>) -> Result<Answer> {
+            Ok(numbers.iter().sum::<u64>().into())
+        }
+    }
+
+    #[test]
+    fn test_bench_times_parse_and_solved_parts() -> Result<()> {
+        let sampling = Sampling {
+            target: Duration::ZERO,
+            min_runs: 3,
+            max_runs: 3,
+        };
+
+        let measurements = bench::<Sum>("1 2 3", &sampling)?;
+
+        let stages = measurements.iter().map(|m| m.stage).collect::<Vec<_>>();
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part(1)]);
+        assert!(measurements.iter().all(|m| m.runs == 3));
+        assert!(measurements.iter().all(|m| m.min <= m.median));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench_reports_parse_errors() {
+        assert!(bench::<Sum>("1 two 3", &Sampling::default()).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::input::InputNotFound;
+use nom::Offset;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can go wrong between reading a day's input and answering it.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading `path` failed; `-` stands for stdin.
+    Io { path: PathBuf, source: io::Error },
+    /// None of the places a day's input could live held it.
+    InputNotFound(InputNotFound),
+    /// The input doesn't match the puzzle's format. `line` and `column` count from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well formed but has no answer.
+    NoSolution(String),
+    /// Something the solution relies on doesn't hold.
+    Invariant(String),
+    /// The day hasn't solved this part yet.
+    Unsolved,
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// A parse error at the start of `rest`, which must be a slice of `input`.
+    pub fn parse_at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let before = &input[..input.offset(rest)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Error::Parse {
+            line,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Where and why a nom parser over `input` gave up.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Error::parse_at(input, e.input, format!("expected {}", e.code.description()))
+            }
+            nom::Err::Incomplete(_) => {
+                Error::parse_at(input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Error::NoSolution(message.into())
+    }
+
+    pub fn invariant(message: impl Into<String>) -> Self {
+        Error::Invariant(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::InputNotFound(e) => write!(f, "{}", e),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::Invariant(message) => write!(f, "invariant violated: {}", message),
+            Error::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::InputNotFound(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputNotFound> for Error {
+    fn from(e: InputNotFound) -> Self {
+        Error::InputNotFound(e)
+    }
+}
+
+/// Runs a nom parser over a day's input, turning a failure into
+/// [`Error::Parse`] at the line and column where it gave up.
+///
+/// Input left over after the parser finishes is not an error; parsers that
+/// must consume everything wrap themselves in `all_consuming`.
+pub fn run_parser<'a, O, P>(mut parser: P, input: &'a str) -> Result<O, Error>
+where
+    P: nom::Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    parser
+        .parse(input)
+        .map(|(_rest, output)| output)
+        .map_err(|e| Error::from_nom(input, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Parser;
+    use nom::character::complete::{digit1, line_ending};
+    use nom::multi::separated_list1;
+
+    #[test]
+    fn test_parse_at_counts_lines_and_columns_from_one() {
+        let input = "12\n34\nab";
+
+        let at = |rest: &str| match Error::parse_at(input, rest, "digit") {
+            Error::Parse { line, column, .. } => (line, column),
+            e => panic!("expected a parse error, got {}", e),
+        };
+
+        assert_eq!(at(&input[7..]), (3, 2));
+        assert_eq!(at(&input[3..4]), (2, 1));
+    }
+
+    #[test]
+    fn test_run_parser_points_at_the_failure() {
+        let numbers = |input| {
+            nom::combinator::all_consuming(separated_list1(line_ending, digit1)).parse(input)
+        };
+
+        assert_eq!(run_parser(numbers, "1\n2").ok(), Some(vec!["1", "2"]));
+        assert_eq!(
+            run_parser(numbers, "1\n2\n3x").unwrap_err().to_string(),
+            "parse error at line 3, column 2: expected End of file"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Day, Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory inside each day's crate holding the puzzle's worked examples.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// The answer the puzzle gives for one part's example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: u8,
+    pub answer: String,
+    /// The solution is known to get this one wrong, so a mismatch is not a
+    /// failure but a match is worth hearing about.
+    pub known_failure: bool,
+}
+
+impl Day {
+    /// `examples/partN.txt`, or `examples/part1.txt` when the parts share an example.
+    pub fn example_path(&self, part: u8) -> PathBuf {
+        let dir = Path::new(self.dir).join(EXAMPLES_DIR);
+        let path = dir.join(format!("part{}.txt", part));
+        if path.is_file() {
+            path
+        } else {
+            dir.join("part1.txt")
+        }
+    }
+
+    /// The answers the examples should give, from `examples/answers.txt`.
+    ///
+    /// One answer per line as `part answer`, with a `!` before the part for
+    /// a [known failure](ExampleAnswer::known_failure). Blank lines and lines
+    /// starting with `#` are ignored.
+    pub fn example_answers(&self) -> Result<Vec<ExampleAnswer>> {
+        let path = Path::new(self.dir).join(EXAMPLES_DIR).join("answers.txt");
+        let contents = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+
+        let mut answers = Vec::new();
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_error = |message: String| Error::Parse {
+                line: idx + 1,
+                column: 1,
+                message: format!("{}: {}", path.display(), message),
+            };
+            let Some((part, answer)) = line.split_once(char::is_whitespace) else {
+                return Err(parse_error("expected `part answer`".to_string()));
+            };
+            let (part, known_failure) = match part.strip_prefix('!') {
+                Some(part) => (part, true),
+                None => (part, false),
+            };
+            let part = part
+                .parse()
+                .map_err(|e| parse_error(format!("part: {}", e)))?;
+            answers.push(ExampleAnswer {
+                part,
+                answer: answer.trim().to_string(),
+                known_failure,
+            });
+        }
+        Ok(answers)
+    }
+}
+
+/// Runs every part that has an example answer and checks what it gives.
+/// Known failures must still get it wrong, so fixing one is noticed.
+///
+/// Each day's tests call this, so `examples/answers.txt` stays in step with
+/// the solution.
+pub fn check_examples(day: &Day) -> Result<()> {
+    let answers = day.example_answers()?;
+    if answers.is_empty() {
+        return Err(Error::invariant(format!(
+            "no example answers for {} day {}",
+            day.year, day.day
+        )));
+    }
+
+    let mut mismatches = Vec::new();
+    for ExampleAnswer {
+        part,
+        answer: expected,
+        known_failure,
+    } in answers
+    {
+        let path = day.example_path(part);
+        let input = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        let solved = (day.solve)(&input, &[part])?;
+        let answer = match solved.parts.into_iter().next() {
+            Some(result) => result.answer?.to_string(),
+            None => return Err(Error::Unsolved),
+        };
+        if known_failure && answer == expected {
+            mismatches.push(format!(
+                "{}: got {} as expected, so it is no longer a known failure",
+                day.label(part),
+                answer
+            ));
+        } else if !known_failure && answer != expected {
+            mismatches.push(format!(
+                "{}: got {}, expected {}",
+                day.label(part),
+                answer,
+                expected
+            ));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::invariant(mismatches.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(lines: &Vec<&str>) -> Result<Answer> {
+            Ok(lines.len().into())
+        }
+
+        fn part2(lines: &Vec<&str>) -> Result<Answer> {
+            Ok(lines.concat().into())
+        }
+    }
+
+    /// A day whose crate directory is a fresh temporary directory holding `files`.
+    fn day_with_examples(name: &str, files: &[(&str, &str)]) -> Day {
+        let dir = std::env::temp_dir().join(format!("aoc-core-{}-{}", name, std::process::id()));
+        let examples = dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&examples).unwrap();
+        for (file, contents) in files {
+            fs::write(examples.join(file), contents).unwrap();
+        }
+        let dir = dir.to_str().unwrap().to_string();
+        Day::new::<Count>(2024, 1, Box::leak(dir.into_boxed_str()))
+    }
+
+    #[test]
+    fn test_parts_share_part1_example_by_default() {
+        let day = day_with_examples("shared", &[("part1.txt", "a\nb\n")]);
+
+        assert_eq!(day.example_path(2), day.example_path(1));
+        assert!(day.example_path(1).ends_with("examples/part1.txt"));
+    }
+
+    #[test]
+    fn test_check_examples_uses_each_parts_example() -> Result<()> {
+        let day = day_with_examples(
+            "per-part",
+            &[
+                ("part1.txt", "a\nb\nc\n"),
+                ("part2.txt", "x\ny\n"),
+                ("answers.txt", "# part answer\n1 3\n2 xy\n"),
+            ],
+        );
+
+        check_examples(&day)
+    }
+
+    #[test]
+    fn test_check_examples_reports_mismatches() {
+        let day = day_with_examples(
+            "mismatch",
+            &[("part1.txt", "a\nb\n"), ("answers.txt", "1 2\n2 abc\n")],
+        );
+
+        let message = check_examples(&day).unwrap_err().to_string();
+
+        assert_eq!(
+            message,
+            "invariant violated: Day 1 Part 2: got ab, expected abc"
+        );
+    }
+
+    #[test]
+    fn test_known_failures_must_still_fail() -> Result<()> {
+        let failing = day_with_examples(
+            "known-failure",
+            &[("part1.txt", "a\nb\n"), ("answers.txt", "!1 3\n2 ab\n")],
+        );
+        check_examples(&failing)?;
+
+        let fixed = day_with_examples(
+            "fixed-failure",
+            &[("part1.txt", "a\nb\n"), ("answers.txt", "!1 2\n")],
+        );
+        assert_eq!(
+            check_examples(&fixed).unwrap_err().to_string(),
+            "invariant violated: Day 1 Part 1: got 2 as expected, so it is no longer a known failure"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Day, Error, Result};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory laid out as `<year>/day-NN.txt`.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Passing this as the input path reads the puzzle input from stdin.
+pub const STDIN: &str = "-";
+
+/// None of the candidate paths for a day held an input file.
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub year: u16,
+    pub day: u8,
+    pub tried: Vec<PathBuf>,
+}
+
+impl fmt::Display for InputNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no input found for {} day {}, tried:",
+            self.year, self.day
+        )?;
+        for path in &self.tried {
+            write!(f, "\n  {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputNotFound {}
+
+/// Works out where a day's puzzle input lives and reads it.
+///
+/// An explicit `input` wins; otherwise the inputs directory is searched,
+/// then the day's own crate directory.
+#[derive(Clone, Debug, Default)]
+pub struct InputLocator {
+    pub input: Option<PathBuf>,
+    pub inputs_dir: Option<PathBuf>,
+}
+
+impl InputLocator {
+    /// A locator that picks up the inputs directory from `AOC_INPUTS_DIR`.
+    pub fn from_env() -> Self {
+        InputLocator {
+            input: None,
+            inputs_dir: std::env::var_os(INPUTS_DIR_VAR).map(PathBuf::from),
+        }
+    }
+
+    /// Every path that would be tried for `day`, in order.
+    pub fn candidates(&self, day: &Day) -> Vec<PathBuf> {
+        if let Some(input) = &self.input {
+            return vec![input.clone()];
+        }
+
+        let mut candidates = Vec::new();
+        if let Some(dir) = &self.inputs_dir {
+            candidates.push(
+                dir.join(day.year.to_string())
+                    .join(format!("day-{:02}.txt", day.day)),
+            );
+        }
+        candidates.push(Path::new(day.dir).join("input.txt"));
+        candidates
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String> {
+        match &self.input {
+            Some(path) if path.as_os_str() == STDIN => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::io(STDIN, e))?;
+                Ok(input)
+            }
+            Some(path) => fs::read_to_string(path).map_err(|e| Error::io(path, e)),
+            None => {
+                let tried = self.candidates(day);
+                for path in &tried {
+                    if path.is_file() {
+                        return fs::read_to_string(path).map_err(|e| Error::io(path, e));
+                    }
+                }
+                Err(Error::InputNotFound(InputNotFound {
+                    year: day.year,
+                    day: day.day,
+                    tried,
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Solution};
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> Result<&str> {
+            Ok(input)
+        }
+
+        fn part1(input: &&str) -> Result<Answer> {
+            Ok((*input).into())
+        }
+    }
+
+    const DAY: Day = Day::new::<Echo>(2024, 7, "/nonexistent/2024/day-07");
+
+    #[test]
+    fn test_candidates_check_inputs_dir_before_crate() {
+        let locator = InputLocator {
+            input: None,
+            inputs_dir: Some(PathBuf::from("/inputs")),
+        };
+
+        assert_eq!(
+            locator.candidates(&DAY),
+            vec![
+                PathBuf::from("/inputs/2024/day-07.txt"),
+                PathBuf::from("/nonexistent/2024/day-07/input.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explicit_input_is_the_only_candidate() {
+        let locator = InputLocator {
+            input: Some(PathBuf::from("puzzle.txt")),
+            inputs_dir: Some(PathBuf::from("/inputs")),
+        };
+
+        assert_eq!(locator.candidates(&DAY), vec![PathBuf::from("puzzle.txt")]);
+    }
+
+    #[test]
+    fn test_missing_input_lists_every_path_tried() {
+        let locator = InputLocator {
+            input: None,
+            inputs_dir: Some(PathBuf::from("/nonexistent/inputs")),
+        };
+
+        let message = locator.read(&DAY).unwrap_err().to_string();
+
+        assert!(message.contains("2024 day 7"));
+        assert!(message.contains("/nonexistent/inputs/2024/day-07.txt"));
+        assert!(message.contains("/nonexistent/2024/day-07/input.txt"));
+    }
+}
@@ -0,0 +1,222 @@
+mod bench;
+mod error;
+mod example;
+mod input;
+pub mod parse;
+
+pub use bench::{BenchFn, Measurement, Sampling, Stage};
+pub use error::{Error, run_parser};
+pub use example::{EXAMPLES_DIR, ExampleAnswer, check_examples};
+pub use input::{INPUTS_DIR_VAR, InputLocator, InputNotFound, STDIN};
+
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A puzzle answer, as it would be typed into the answer box.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i128::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, i128, isize, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// One day's puzzle: parse the input once, then answer either part from it.
+pub trait Solution {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    fn part1(_parsed: &Self::Parsed<'_>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+
+    fn part2(_parsed: &Self::Parsed<'_>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// The parts a day answered, along with how long parsing took.
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+pub type SolveFn = fn(&str, &[u8]) -> Result<Solved>;
+
+fn run_part<S: Solution>(parsed: &S::Parsed<'_>, part: u8) -> Result<Answer> {
+    match part {
+        1 => S::part1(parsed),
+        2 => S::part2(parsed),
+        _ => Err(Error::invariant(format!("there is no part {}", part))),
+    }
+}
+
+/// Parses `input` once and runs the requested parts, skipping unsolved ones.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = run_part::<S>(&parsed, part);
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .filter(|result| !matches!(result.answer, Err(Error::Unsolved)))
+        .collect();
+
+    Ok(Solved { parse_time, parts })
+}
+
+/// A single puzzle day as seen by the `aoc` runner and by each day's own binary.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Crate directory, so the input resolves no matter where we're run from.
+    pub dir: &'static str,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8, dir: &'static str) -> Self {
+        Day {
+            year,
+            day,
+            dir,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    pub fn label(&self, part: u8) -> String {
+        format!("Day {} Part {}", self.day, part)
+    }
+}
+
+/// Entry point shared by every day's `main.rs`: runs whichever parts the day has.
+///
+/// An optional first argument names the input file, or `-` for stdin.
+pub fn main(day: &Day) {
+    let locator = InputLocator {
+        input: std::env::args_os().nth(1).map(PathBuf::from),
+        ..InputLocator::from_env()
+    };
+    let results = locator
+        .read(day)
+        .and_then(|input| (day.solve)(&input, &[1, 2]));
+
+    let mut ok = true;
+    match results {
+        Ok(solved) => {
+            for PartResult { part, answer, .. } in solved.parts {
+                match answer {
+                    Ok(answer) => println!("[{}] Result: {}", day.label(part), answer),
+                    Err(e) => {
+                        eprintln!("[{} {}] Error: {}", day.year, day.label(part), e);
+                        ok = false;
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("[{} Day {}] Error: {}", day.year, day.day, e);
+            ok = false;
+        }
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Parsed<'a> = i64;
+
+        fn parse(input: &str) -> Result<i64> {
+            input
+                .trim()
+                .parse()
+                .map_err(|e| Error::parse_at(input, input, format!("{}", e)))
+        }
+
+        fn part1(n: &i64) -> Result<Answer> {
+            Ok((n * 2).into())
+        }
+    }
+
+    #[test]
+    fn test_solve_skips_unsolved_parts() -> Result<()> {
+        let results = solve::<Doubler>("21\n", &[1, 2])?.parts;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part, 1);
+        assert_eq!(results[0].answer.as_ref().ok(), Some(&Answer::Number(42)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_reports_parse_errors() {
+        assert!(solve::<Doubler>("not a number", &[1]).is_err());
+    }
+
+    #[test]
+    fn test_answers_beyond_i128_keep_their_digits() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i128::MAX as u128), Answer::Number(i128::MAX));
+    }
+}
@@ -0,0 +1,146 @@
+//! nom parsers for the shapes puzzle inputs keep coming in. Grids live in
+//! `aoc_grid`, which has its own `parse_grid` and `char_grid`.
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use nom::{
+    IResult, Parser,
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0},
+    combinator::{all_consuming, opt, recognize},
+    error::Error as NomError,
+    multi::{many0, separated_list1},
+    sequence::{delimited, separated_pair},
+};
+
+use crate::{Error, Result};
+
+/// Parses all of a day's input, ignoring blank lines before it and
+/// whitespace after it. The first line keeps its indentation, which matters
+/// for inputs laid out in columns.
+///
+/// Anything else left over is an error pointing at where it starts.
+pub fn parse_all<'a, O, P>(parser: P, input: &'a str) -> Result<O>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    all_consuming(delimited(many0((space0, line_ending)), parser, multispace0))
+        .parse(input)
+        .map(|(_rest, output)| output)
+        .map_err(|e| Error::from_nom(input, e))
+}
+
+/// A run of digits as any integer type. Fails rather than wrapping when the
+/// number does not fit.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    digit1.map_res(str::parse).parse(input)
+}
+
+/// Like [`unsigned`], with an optional leading `-` or `+`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    recognize((opt(one_of("+-")), digit1))
+        .map_res(str::parse)
+        .parse(input)
+}
+
+/// One `item` per line. Does not consume a trailing newline.
+pub fn lines_of<'a, O, P>(
+    item: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// The end of a line followed by an empty one, as between sections.
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    (line_ending, line_ending).map(|_| ()).parse(input)
+}
+
+/// Sections of the same shape separated by a single [`blank_line`].
+pub fn blank_line_separated_sections<'a, O, P>(
+    section: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    separated_list1(blank_line, section)
+}
+
+/// `item`s separated by commas with no spaces, like `1,2,3`.
+pub fn comma_list<'a, O, P>(
+    item: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    separated_list1(char(','), item)
+}
+
+/// An inclusive range written `start-end`, such as `11-22`.
+pub fn range<'a, T, P>(
+    bound: P,
+) -> impl Parser<&'a str, Output = RangeInclusive<T>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = T, Error = NomError<&'a str>> + Clone,
+{
+    separated_pair(bound.clone(), char('-'), bound).map(|(start, end)| start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u8>("255,"), Ok((",", 255)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(unsigned::<u32>("-1").is_err());
+        assert_eq!(signed::<i64>("-12 3"), Ok((" 3", -12)));
+        assert_eq!(signed::<i32>("+7"), Ok(("", 7)));
+    }
+
+    #[test]
+    fn test_shapes() -> Result<()> {
+        let input = "1-3,5-5\n7-8\n\n10\n11\n";
+        let ranges = lines_of(comma_list(range(unsigned::<u32>)));
+        let numbers = lines_of(unsigned::<u32>);
+
+        let (ranges, numbers) = parse_all(separated_pair(ranges, blank_line, numbers), input)?;
+
+        assert_eq!(ranges, vec![vec![1..=3, 5..=5], vec![7..=8]]);
+        assert_eq!(numbers, vec![10, 11]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sections() -> Result<()> {
+        let sections = parse_all(
+            blank_line_separated_sections(lines_of(signed::<i32>)),
+            "1\n-2\n\n3\n",
+        )?;
+
+        assert_eq!(sections, vec![vec![1, -2], vec![3]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_all_points_at_leftovers() {
+        assert_eq!(
+            parse_all(lines_of(unsigned::<u32>), "1\n2x\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, column 2: expected End of file"
+        );
+    }
+
+    #[test]
+    fn test_parse_all_keeps_first_line_indentation() -> Result<()> {
+        let line = nom::bytes::complete::take_while1(|c| c != '\n');
+        let lines = parse_all(lines_of(line), "\n  \n 1\n2 \n\n")?;
+
+        assert_eq!(lines, vec![" 1", "2 "]);
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+mod rollback;
+
+pub use rollback::{RollbackDsu, Snapshot};
+
+/// Disjoint sets over the elements `0..len`, for merging things into
+/// connected groups one link at a time.
+#[derive(Clone, Debug)]
+pub struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl Dsu {
+    /// `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Dsu {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of `x`'s set. Points everything on the way at it,
+    /// so later lookups are shorter.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        let mut at = x;
+        while at != root {
+            at = std::mem::replace(&mut self.parent[at], root);
+        }
+        root
+    }
+
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets holding `a` and `b`. False if they were already one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements share `x`'s set, `x` included.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// How many sets there are.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Whether everything has been merged into one set.
+    pub fn is_fully_connected(&self) -> bool {
+        self.components == 1
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|x| self.size[x])
+            .collect()
+    }
+
+    /// The members of every set, each in ascending order. Sets are ordered by
+    /// their smallest member.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut slot = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for x in 0..self.len() {
+            let root = self.root(x);
+            if slot[root] == usize::MAX {
+                slot[root] = groups.len();
+                groups.push(Vec::with_capacity(self.size[root]));
+            }
+            groups[slot[root]].push(x);
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_tracks_components() {
+        let mut dsu = Dsu::new(6);
+
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+
+        assert!(dsu.same_set(0, 3));
+        assert!(!dsu.same_set(0, 4));
+        assert_eq!(dsu.set_size(2), 4);
+        assert_eq!(dsu.components(), 3);
+        assert!(!dsu.is_fully_connected());
+
+        let mut sizes = dsu.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 4]);
+        assert_eq!(dsu.groups(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_find_handles_long_chains() {
+        let n = 1_000_000;
+        let mut dsu = Dsu::new(n);
+        // Link roots by hand into one long path, which union by size never
+        // builds, to check find does not recurse.
+        for x in 1..n {
+            dsu.parent[x - 1] = x;
+        }
+
+        assert_eq!(dsu.find(0), n - 1);
+        assert_eq!(dsu.parent[0], n - 1);
+    }
+}
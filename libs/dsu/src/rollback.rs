@@ -0,0 +1,100 @@
+/// A [`Dsu`](crate::Dsu) that can undo unions, for searches that try a link
+/// and back out of it.
+///
+/// It skips path compression so every union is a single pointer change that
+/// can be put back, which leaves `find` logarithmic rather than near
+/// constant.
+#[derive(Clone, Debug)]
+pub struct RollbackDsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    /// Each successful union as `(child, root)`, oldest first.
+    history: Vec<(usize, usize)>,
+}
+
+/// A point to [`rollback`](RollbackDsu::rollback) to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snapshot(usize);
+
+impl RollbackDsu {
+    pub fn new(len: usize) -> Self {
+        RollbackDsu {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        self.history.push((b, a));
+        true
+    }
+
+    pub fn same_set(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn set_size(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.history.len())
+    }
+
+    /// Undoes every union made since `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: Snapshot) {
+        let undone = self.history.split_off(snapshot.0);
+        for &(child, root) in undone.iter().rev() {
+            self.parent[child] = child;
+            self.size[root] -= self.size[child];
+            self.components += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_undoes_unions() {
+        let mut dsu = RollbackDsu::new(4);
+        dsu.union(0, 1);
+        let before = dsu.snapshot();
+
+        dsu.union(2, 3);
+        dsu.union(1, 3);
+        assert_eq!(dsu.components(), 1);
+        assert_eq!(dsu.set_size(0), 4);
+
+        dsu.rollback(before);
+        assert_eq!(dsu.components(), 3);
+        assert!(dsu.same_set(0, 1));
+        assert!(!dsu.same_set(1, 2));
+        assert_eq!(dsu.set_size(3), 1);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::Point2;
+
+/// Something that moves a point one step, with `y` growing downwards.
+pub trait Heading: Copy {
+    fn offset(self) -> Point2;
+}
+
+impl Point2 {
+    /// The point one step away in `heading`.
+    pub fn step(self, heading: impl Heading) -> Self {
+        self + heading.offset()
+    }
+}
+
+/// The four directions along the axes, clockwise from up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// The eight directions to a touching cell, clockwise from up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    fn turned(self, quarter_turns: usize) -> Self {
+        Self::ALL[(self as usize + quarter_turns) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turned(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turned(3)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turned(2)
+    }
+}
+
+impl Heading for Dir4 {
+    fn offset(self) -> Point2 {
+        Dir8::from(self).offset()
+    }
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn turned(self, eighth_turns: usize) -> Self {
+        Self::ALL[(self as usize + eighth_turns) % 8]
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turned(1)
+    }
+
+    /// An eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turned(7)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turned(4)
+    }
+}
+
+impl Heading for Dir8 {
+    fn offset(self) -> Point2 {
+        let (x, y) = match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+    }
+
+    #[test]
+    fn test_steps() {
+        let p = Point2::new(2, 2);
+
+        assert_eq!(p.step(Dir4::Up), Point2::new(2, 1));
+        assert_eq!(p.step(Dir8::DownLeft), Point2::new(1, 3));
+        for dir in Dir8::ALL {
+            assert_eq!(p.step(dir).step(dir.opposite()), p);
+        }
+    }
+}
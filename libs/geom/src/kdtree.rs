@@ -0,0 +1,382 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+use aoc_dsu::Dsu;
+
+use crate::Point3;
+
+/// A k-d tree over 3D points, for nearest neighbour searches that skip most
+/// of the points.
+///
+/// The tree is implicit in `order`: a subtree is a slice of it with its root
+/// in the middle, splitting on x, y and z in turn by depth.
+#[derive(Clone, Debug)]
+pub struct KdTree {
+    points: Vec<Point3>,
+    order: Vec<usize>,
+}
+
+/// A candidate edge as `(squared distance, lower index, higher index)`.
+/// Comparing whole tuples breaks ties between equally long edges the same
+/// way everywhere.
+type Edge = (i64, usize, usize);
+
+fn coord(p: Point3, axis: usize) -> i64 {
+    match axis {
+        0 => p.x,
+        1 => p.y,
+        _ => p.z,
+    }
+}
+
+fn build(points: &[Point3], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mid = order.len() / 2;
+    let axis = depth % 3;
+    order.select_nth_unstable_by_key(mid, |&i| coord(points[i], axis));
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+impl KdTree {
+    /// A tree over `points`, which are referred to by their index in it.
+    pub fn new(points: &[Point3]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+        KdTree {
+            points: points.to_vec(),
+            order,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The index of the point nearest `query` among those `keep` accepts,
+    /// with its squared distance. Ties go to the lowest index.
+    pub fn nearest(&self, query: Point3, keep: impl Fn(usize) -> bool) -> Option<(usize, i64)> {
+        let mut best = None;
+        self.search(
+            0..self.len(),
+            0,
+            query,
+            &|i| keep(i).then_some((i, i)),
+            &|_| false,
+            &mut best,
+        );
+        best.map(|(d, i, _)| (i, d))
+    }
+
+    /// Every pair of points as `(a, b, squared distance)` with `a < b`,
+    /// closest first, generated lazily.
+    ///
+    /// Each point runs its own best-first search of the tree for the points
+    /// after it, and the pairs are merged from the heads of those searches.
+    /// All `n` searches start at once, each needing its first pair, so that
+    /// alone costs about `n log n` time. Every search keeps a heap of the
+    /// subtrees it has yet to open, which is small on spread-out points but
+    /// can grow towards `n` entries on clustered ones, so memory is between
+    /// `n log n` and `n²`. Each pair taken after that costs about `log n`,
+    /// against sorting all `n²` pairs up front. Ties come out in order of
+    /// `a`, then `b`.
+    pub fn closest_pairs(&self) -> impl Iterator<Item = (usize, usize, i64)> + '_ {
+        let mut searches: Vec<Neighbours> =
+            (0..self.len()).map(|a| Neighbours::new(self, a)).collect();
+        let mut heads: BinaryHeap<Reverse<Edge>> = searches
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(a, search)| search.next().map(|(d, b)| Reverse((d, a, b))))
+            .collect();
+        std::iter::from_fn(move || {
+            let Reverse((d, a, b)) = heads.pop()?;
+            if let Some((d, b)) = searches[a].next() {
+                heads.push(Reverse((d, a, b)));
+            }
+            Some((a, b, d))
+        })
+    }
+
+    /// Depth-first search of the subtree over `slice`, improving on `best`.
+    /// `candidate` orders a point as an edge, or rejects it, and subtrees
+    /// whose root sits where `skip` holds are passed over whole.
+    fn search(
+        &self,
+        slice: Range<usize>,
+        depth: usize,
+        query: Point3,
+        candidate: &dyn Fn(usize) -> Option<(usize, usize)>,
+        skip: &dyn Fn(usize) -> bool,
+        best: &mut Option<Edge>,
+    ) {
+        if slice.is_empty() {
+            return;
+        }
+        let mid = (slice.start + slice.end) / 2;
+        if skip(mid) {
+            return;
+        }
+        let i = self.order[mid];
+        let point = self.points[i];
+        if let Some((a, b)) = candidate(i) {
+            let edge = (point.squared_euclidean(query), a, b);
+            if best.is_none_or(|best| edge < best) {
+                *best = Some(edge);
+            }
+        }
+
+        let axis = depth % 3;
+        let diff = coord(query, axis) - coord(point, axis);
+        let (near, far) = if diff < 0 {
+            (slice.start..mid, mid + 1..slice.end)
+        } else {
+            (mid + 1..slice.end, slice.start..mid)
+        };
+        self.search(near, depth + 1, query, candidate, skip, best);
+        if best.is_none_or(|(d, _, _)| diff * diff <= d) {
+            self.search(far, depth + 1, query, candidate, skip, best);
+        }
+    }
+
+    /// Records in `uniform`, at each subtree's root, the component every
+    /// point of the subtree is in, if they share one. Returns that for the
+    /// subtree over `slice`, which must not be empty.
+    fn uniform_components(
+        &self,
+        slice: Range<usize>,
+        component: &[usize],
+        uniform: &mut [Option<usize>],
+    ) -> Option<usize> {
+        let mid = (slice.start + slice.end) / 2;
+        let mut shared = Some(component[self.order[mid]]);
+        for half in [slice.start..mid, mid + 1..slice.end] {
+            if !half.is_empty() && self.uniform_components(half, component, uniform) != shared {
+                shared = None;
+            }
+        }
+        uniform[mid] = shared;
+        shared
+    }
+}
+
+/// Part of the tree still to look at in a [`Neighbours`] search: a subtree
+/// when `point` is `None`, otherwise a single point. `distance` is the
+/// point's squared distance, or for a subtree a lower bound on those of its
+/// points, taken from how far the query is past each splitting plane.
+///
+/// Fields are ordered so the smallest compares first, with subtrees ahead of
+/// points at the same distance, so that no point can be passed by one still
+/// hidden in an unopened subtree.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Pending {
+    distance: i64,
+    point: Option<usize>,
+    slice: (usize, usize),
+    depth: usize,
+    offsets: [i64; 3],
+}
+
+/// The points after `query` in index order, nearest first, found by a
+/// best-first search of the tree that opens subtrees only when needed.
+struct Neighbours<'t> {
+    tree: &'t KdTree,
+    query: usize,
+    pending: BinaryHeap<Reverse<Pending>>,
+}
+
+impl<'t> Neighbours<'t> {
+    fn new(tree: &'t KdTree, query: usize) -> Self {
+        let mut pending = BinaryHeap::new();
+        pending.push(Reverse(Pending {
+            distance: 0,
+            point: None,
+            slice: (0, tree.len()),
+            depth: 0,
+            offsets: [0; 3],
+        }));
+        Neighbours {
+            tree,
+            query,
+            pending,
+        }
+    }
+}
+
+impl Iterator for Neighbours<'_> {
+    /// `(squared distance, index)`.
+    type Item = (i64, usize);
+
+    fn next(&mut self) -> Option<(i64, usize)> {
+        let query = self.tree.points[self.query];
+        while let Some(Reverse(next)) = self.pending.pop() {
+            if let Some(j) = next.point {
+                return Some((next.distance, j));
+            }
+
+            let (start, end) = next.slice;
+            if start >= end {
+                continue;
+            }
+            let mid = (start + end) / 2;
+            let j = self.tree.order[mid];
+            let point = self.tree.points[j];
+            if j > self.query {
+                self.pending.push(Reverse(Pending {
+                    distance: point.squared_euclidean(query),
+                    point: Some(j),
+                    slice: (mid, mid),
+                    depth: next.depth,
+                    offsets: next.offsets,
+                }));
+            }
+
+            let axis = next.depth % 3;
+            let diff = coord(query, axis) - coord(point, axis);
+            let mut far_offsets = next.offsets;
+            far_offsets[axis] = diff;
+            let far_distance = far_offsets.iter().map(|o| o * o).sum();
+            let (near, far) = if diff < 0 {
+                ((start, mid), (mid + 1, end))
+            } else {
+                ((mid + 1, end), (start, mid))
+            };
+            for (slice, distance, offsets) in [
+                (near, next.distance, next.offsets),
+                (far, far_distance, far_offsets),
+            ] {
+                self.pending.push(Reverse(Pending {
+                    distance,
+                    point: None,
+                    slice,
+                    depth: next.depth + 1,
+                    offsets,
+                }));
+            }
+        }
+        None
+    }
+}
+
+/// The edges of a minimum spanning tree over `points`, weighted by squared
+/// distance, as `(a, b, squared distance)`.
+///
+/// Uses Borůvka's algorithm: each round, every component finds its nearest
+/// point outside itself through a [`KdTree`], skipping subtrees that lie
+/// wholly inside it, and all of those edges are added at once. Each round at
+/// least halves the components, so this takes about `n log² n` time rather
+/// than sorting all `n²` pairs.
+pub fn euclidean_mst(points: &[Point3]) -> Vec<(usize, usize, i64)> {
+    let tree = KdTree::new(points);
+    let mut dsu = Dsu::new(points.len());
+    let mut edges = Vec::with_capacity(points.len().saturating_sub(1));
+    let mut uniform = vec![None; points.len()];
+
+    while dsu.components() > 1 {
+        let component: Vec<usize> = (0..points.len()).map(|i| dsu.find(i)).collect();
+        tree.uniform_components(0..points.len(), &component, &mut uniform);
+
+        let mut cheapest: Vec<Option<Edge>> = vec![None; points.len()];
+        for (i, &point) in points.iter().enumerate() {
+            let own = component[i];
+            tree.search(
+                0..points.len(),
+                0,
+                point,
+                &|j| (component[j] != own).then_some((i.min(j), i.max(j))),
+                &|root| uniform[root] == Some(own),
+                &mut cheapest[own],
+            );
+        }
+
+        for (a, b, d) in cheapest.into_iter().flatten().map(|(d, a, b)| (a, b, d)) {
+            if dsu.union(a, b) {
+                edges.push((a, b, d));
+            }
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points from a small linear congruential generator, crowded into a
+    /// small cube so that equal distances and repeated points turn up.
+    fn scattered(n: usize, side: i64) -> Vec<Point3> {
+        let mut state = 12345u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % side as u64) as i64
+        };
+        (0..n)
+            .map(|_| Point3::new(next(), next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn test_nearest_matches_brute_force() {
+        let points = scattered(300, 50);
+        let tree = KdTree::new(&points);
+
+        for (i, &query) in points.iter().enumerate() {
+            let expected = (0..points.len())
+                .filter(|&j| j != i)
+                .map(|j| (points[j].squared_euclidean(query), j))
+                .min()
+                .map(|(d, j)| (j, d));
+
+            assert_eq!(tree.nearest(query, |j| j != i), expected);
+        }
+        assert_eq!(tree.nearest(Point3::ORIGIN, |_| false), None);
+    }
+
+    #[test]
+    fn test_closest_pairs_come_in_order() {
+        let points = scattered(120, 6);
+        let mut expected: Vec<Edge> = (0..points.len())
+            .flat_map(|a| (a + 1..points.len()).map(move |b| (a, b)))
+            .map(|(a, b)| (points[a].squared_euclidean(points[b]), a, b))
+            .collect();
+        expected.sort_unstable();
+
+        let pairs: Vec<Edge> = KdTree::new(&points)
+            .closest_pairs()
+            .map(|(a, b, d)| (d, a, b))
+            .collect();
+
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn test_euclidean_mst_matches_kruskal() {
+        for (n, side) in [(0, 10), (1, 10), (2, 10), (200, 8), (500, 1000)] {
+            let points = scattered(n, side);
+
+            let mut pairs: Vec<Edge> = (0..n)
+                .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                .map(|(a, b)| (points[a].squared_euclidean(points[b]), a, b))
+                .collect();
+            pairs.sort_unstable();
+            let mut dsu = Dsu::new(n);
+            let expected: Vec<i64> = pairs
+                .into_iter()
+                .filter(|&(_, a, b)| dsu.union(a, b))
+                .map(|(d, _, _)| d)
+                .collect();
+
+            let mut weights: Vec<i64> = euclidean_mst(&points).iter().map(|e| e.2).collect();
+            weights.sort_unstable();
+
+            assert_eq!(weights, expected, "{} points", n);
+        }
+    }
+}
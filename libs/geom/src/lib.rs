@@ -0,0 +1,7 @@
+mod dir;
+mod kdtree;
+mod point;
+
+pub use dir::{Dir4, Dir8, Heading};
+pub use kdtree::{KdTree, euclidean_mst};
+pub use point::{Point2, Point3};
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A node's position in its [`Graph`], handed out in the order names are
+/// first seen.
+pub type NodeId = usize;
+
+/// A directed graph whose nodes are named by strings borrowed from the input.
+///
+/// Names are interned as they are added, so algorithms work on dense
+/// [`NodeId`]s and only turn them back into names for output.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Graph<'a> {
+    names: Vec<&'a str>,
+    ids: HashMap<&'a str, NodeId>,
+    edges: Vec<Vec<NodeId>>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id for `name`, adding it as a node with no edges if it is new.
+    pub fn intern(&mut self, name: &'a str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name);
+        self.ids.insert(name, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// Adds an edge between two named nodes, adding the nodes if needed.
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.edges[from].push(to);
+    }
+
+    /// The id of the node called `name`, if there is one.
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name of node `id`. Panics if there is no such node.
+    pub fn name(&self, id: NodeId) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every node id, in the order the nodes were added.
+    pub fn nodes(&self) -> std::ops::Range<NodeId> {
+        0..self.len()
+    }
+
+    /// The nodes `id` has an edge to, in the order the edges were added.
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        &self.edges[id]
+    }
+
+    /// Every edge as `(from, to)`.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(|(from, tos)| tos.iter().map(move |&to| (from, to)))
+    }
+
+    /// The graph restricted to `names`, keeping only edges with both ends
+    /// among them. Nodes are numbered in the order given, and names not in
+    /// this graph become nodes without edges.
+    pub fn subgraph(&self, names: impl IntoIterator<Item = &'a str>) -> Graph<'a> {
+        let mut sub = Graph::new();
+        let mut old_ids = Vec::new();
+        for name in names {
+            if sub.id(name).is_none() {
+                sub.intern(name);
+                old_ids.push(self.id(name));
+            }
+        }
+        for (new_from, old_from) in old_ids.iter().enumerate() {
+            let Some(old_from) = *old_from else {
+                continue;
+            };
+            for &old_to in self.successors(old_from) {
+                if let Some(new_to) = sub.id(self.name(old_to)) {
+                    sub.edges[new_from].push(new_to);
+                }
+            }
+        }
+        sub
+    }
+
+    /// The graph in Graphviz DOT format, for eyeballing with `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for id in self.nodes() {
+            let _ = writeln!(dot, "    {:?};", self.name(id));
+        }
+        for (from, to) in self.edges() {
+            let _ = writeln!(dot, "    {:?} -> {:?};", self.name(from), self.name(to));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for Graph<'a> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning_and_subgraph() {
+        let graph = Graph::from_iter([("a", "b"), ("b", "c"), ("a", "c"), ("c", "d")]);
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("z"), None);
+        assert_eq!(graph.successors(0), &[1, 2]);
+
+        let sub = graph.subgraph(["c", "a", "z"]);
+        assert_eq!(sub.name(0), "c");
+        assert_eq!(sub.edges().collect::<Vec<_>>(), vec![(1, 0)]);
+        assert!(sub.successors(2).is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = Graph::from_iter([("a", "b")]);
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"a\";\n    \"b\";\n    \"a\" -> \"b\";\n}\n"
+        );
+    }
+}
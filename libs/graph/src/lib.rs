@@ -0,0 +1,7 @@
+mod graph;
+mod order;
+mod parse;
+
+pub use graph::{Graph, NodeId};
+pub use order::Cycle;
+pub use parse::adjacency_list;
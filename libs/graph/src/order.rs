@@ -0,0 +1,242 @@
+use std::fmt;
+
+use crate::{Graph, NodeId};
+
+/// A loop found where the graph had to be acyclic, as the names along it.
+/// The last node has an edge back to the first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<'a> {
+    pub nodes: Vec<&'a str>,
+}
+
+impl fmt::Display for Cycle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for name in &self.nodes {
+            write!(f, "{} -> ", name)?;
+        }
+        write!(f, "{}", self.nodes.first().unwrap_or(&""))
+    }
+}
+
+impl std::error::Error for Cycle<'_> {}
+
+impl From<Cycle<'_>> for aoc_core::Error {
+    fn from(cycle: Cycle<'_>) -> Self {
+        aoc_core::Error::no_solution(format!("the graph has a cycle: {}", cycle))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    New,
+    Open,
+    Done,
+}
+
+impl<'a> Graph<'a> {
+    /// Every node, each before all the nodes it has edges to.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, Cycle<'a>> {
+        let mut marks = vec![Mark::New; self.len()];
+        let mut order = Vec::with_capacity(self.len());
+        for start in self.nodes() {
+            self.depth_first(start, &mut marks, |_| false, |node| order.push(node))?;
+        }
+        order.reverse();
+        Ok(order)
+    }
+
+    /// A cycle somewhere in the graph, if it has one.
+    pub fn find_cycle(&self) -> Option<Cycle<'a>> {
+        self.topological_order().err()
+    }
+
+    /// How many distinct paths lead from `from` to `to`.
+    ///
+    /// Each node's count is worked out once, so this is linear in the part
+    /// of the graph reachable from `from`. Paths stop at `to`, and a cycle
+    /// reachable on the way is an error since it would allow endless paths.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<u64, Cycle<'a>> {
+        let mut marks = vec![Mark::New; self.len()];
+        let mut counts = vec![0u64; self.len()];
+        self.depth_first(
+            from,
+            &mut marks,
+            |node| node == to,
+            |node| {
+                counts[node] = if node == to {
+                    1
+                } else {
+                    self.successors(node).iter().map(|&next| counts[next]).sum()
+                };
+            },
+        )?;
+        Ok(counts[from])
+    }
+
+    /// Groups of nodes that can all reach each other, found with Tarjan's
+    /// algorithm. A group comes after every group it has edges to.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNSEEN: usize = usize::MAX;
+        let mut index = vec![UNSEEN; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in self.nodes() {
+            if index[root] != UNSEEN {
+                continue;
+            }
+            let mut calls = vec![(root, 0)];
+
+            while let Some(frame) = calls.last_mut() {
+                let (node, next) = *frame;
+                if index[node] == UNSEEN {
+                    index[node] = next_index;
+                    low[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(&succ) = self.successors(node).get(next) {
+                    frame.1 += 1;
+                    if index[succ] == UNSEEN {
+                        calls.push((succ, 0));
+                    } else if on_stack[succ] {
+                        low[node] = low[node].min(index[succ]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if low[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Depth-first search from `start` without recursion, calling `finish`
+    /// on each node after all its successors. Nodes where `stop` holds are
+    /// not looked past. Fails on reaching a node that is still open.
+    fn depth_first(
+        &self,
+        start: NodeId,
+        marks: &mut [Mark],
+        stop: impl Fn(NodeId) -> bool,
+        mut finish: impl FnMut(NodeId),
+    ) -> Result<(), Cycle<'a>> {
+        if marks[start] != Mark::New {
+            return Ok(());
+        }
+        marks[start] = Mark::Open;
+        let mut path = vec![(start, 0)];
+
+        while let Some(frame) = path.last_mut() {
+            let (node, next) = *frame;
+            let successors = if stop(node) {
+                &[][..]
+            } else {
+                self.successors(node)
+            };
+            let Some(&succ) = successors.get(next) else {
+                marks[node] = Mark::Done;
+                finish(node);
+                path.pop();
+                continue;
+            };
+            frame.1 += 1;
+            match marks[succ] {
+                Mark::New => {
+                    marks[succ] = Mark::Open;
+                    path.push((succ, 0));
+                }
+                Mark::Open => {
+                    let from = path.iter().position(|&(n, _)| n == succ).unwrap_or(0);
+                    let nodes = path[from..].iter().map(|&(n, _)| self.name(n)).collect();
+                    return Err(Cycle { nodes });
+                }
+                Mark::Done => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names<'a>(graph: &Graph<'a>, ids: &[NodeId]) -> Vec<&'a str> {
+        ids.iter().map(|&id| graph.name(id)).collect()
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let graph = Graph::from_iter([("c", "d"), ("a", "b"), ("b", "c"), ("a", "c")]);
+
+        let order = graph.topological_order().unwrap();
+
+        assert_eq!(names(&graph, &order), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_cycles_are_reported() {
+        let graph = Graph::from_iter([("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+
+        let cycle = graph.find_cycle().unwrap();
+
+        assert_eq!(cycle.to_string(), "a -> b -> c -> a");
+        assert_eq!(graph.count_paths(0, 3), Err(cycle));
+        assert_eq!(graph.count_paths(0, 0), Ok(1));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Two ways from a to d, each of which can go on to e two ways.
+        let graph = Graph::from_iter([
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("d", "e"),
+            ("d", "f"),
+            ("f", "e"),
+        ]);
+        let id = |name| graph.id(name).unwrap();
+
+        assert_eq!(graph.count_paths(id("a"), id("e")), Ok(4));
+        assert_eq!(graph.count_paths(id("a"), id("d")), Ok(2));
+        assert_eq!(graph.count_paths(id("e"), id("a")), Ok(0));
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = Graph::from_iter([("a", "b"), ("b", "a"), ("b", "c"), ("c", "d"), ("d", "c")]);
+
+        let components = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|c| {
+                let mut c = names(&graph, c);
+                c.sort_unstable();
+                c
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(components, vec![vec!["c", "d"], vec!["a", "b"]]);
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_core::parse::lines_of;
+use nom::{
+    IResult, Parser,
+    character::complete::{alphanumeric1, char, space0, space1},
+    multi::many0,
+    sequence::{preceded, separated_pair},
+};
+
+use crate::Graph;
+
+/// Parses lines of `name: a b c`, each giving edges from `name` to the names
+/// after the colon. The list may be empty.
+pub fn adjacency_list(input: &str) -> IResult<&str, Graph<'_>> {
+    let line = separated_pair(
+        alphanumeric1,
+        (space0, char(':')),
+        many0(preceded(space1, alphanumeric1)),
+    );
+    lines_of(line)
+        .map(|lines| {
+            let mut graph = Graph::new();
+            for (from, tos) in lines {
+                graph.intern(from);
+                for to in tos {
+                    graph.add_edge(from, to);
+                }
+            }
+            graph
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Result;
+    use aoc_core::parse::parse_all;
+
+    #[test]
+    fn test_adjacency_list() -> Result<()> {
+        let graph = parse_all(adjacency_list, "you: bbb ccc\nbbb: out\nccc:\n")?;
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.name(3), "out");
+        assert_eq!(graph.successors(graph.id("you").unwrap()), &[1, 2]);
+        assert!(graph.successors(graph.id("ccc").unwrap()).is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,445 @@
+use aoc_geom::{Dir4, Dir8, Heading, Point2};
+use nom::{
+    IResult, Parser,
+    character::complete::{line_ending, none_of},
+    combinator::map_opt,
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in one `Vec`.
+///
+/// Cells are addressed by [`Point2`], `x` counting columns from the left and
+/// `y` rows from the top. Positions are signed, so stepping off an edge is
+/// just a lookup that finds nothing.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns over `cells` given row by row.
+    ///
+    /// Panics if `cells` doesn't fill whole rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// A grid from its rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid::new(width, rows.into_iter().flatten().collect()))
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, pos: Point2) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn pos_of(&self, idx: usize) -> Point2 {
+        Point2::new((idx % self.width) as i64, (idx / self.width) as i64)
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.pos_of(idx), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point2> + use<T> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| self.pos_of(idx))
+    }
+
+    /// Every position whose cell matches `predicate`.
+    pub fn positions_where(
+        &self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> impl Iterator<Item = Point2> {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The cells above, right of, below and left of `pos` that are on the grid.
+    pub fn neighbors4(&self, pos: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.neighbors(pos, Dir4::ALL)
+    }
+
+    /// The up to eight cells touching `pos`, diagonals included.
+    pub fn neighbors8(&self, pos: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.neighbors(pos, Dir8::ALL)
+    }
+
+    fn neighbors<H: Heading>(
+        &self,
+        pos: Point2,
+        headings: impl IntoIterator<Item = H>,
+    ) -> impl Iterator<Item = (Point2, &T)> {
+        headings.into_iter().filter_map(move |heading| {
+            let pos = pos.step(heading);
+            self.get(pos).map(|cell| (pos, cell))
+        })
+    }
+
+    /// Row `y`. Panics if it is off the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Column `x`, top to bottom. Panics if it is off the grid.
+    pub fn column(&self, x: usize) -> Line<'_, T> {
+        assert!(x < self.width, "column {} is off the grid", x);
+        self.line(Point2::new(x as i64, 0), Dir8::Down, self.height)
+    }
+
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = Line<'_, T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every line running down and to the right.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        self.lines(Dir8::DownRight)
+    }
+
+    /// Every line running down and to the left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        self.lines(Dir8::DownLeft)
+    }
+
+    /// Every straight line across the grid heading `dir`, from edge to edge,
+    /// ordered by where they start.
+    ///
+    /// `Dir8::Right` gives the rows, `Dir8::Up` the columns read bottom to top
+    /// and `Dir8::UpLeft` the diagonals read up and to the left.
+    pub fn lines(&self, dir: Dir8) -> impl Iterator<Item = Line<'_, T>> {
+        let back = dir.opposite();
+        self.positions()
+            .filter(move |&pos| !self.contains(pos.step(back)))
+            .map(move |start| self.ray(start, dir))
+    }
+
+    /// The cells from `start` to the edge of the grid heading `dir`. Empty if
+    /// `start` is off the grid.
+    pub fn ray(&self, start: Point2, dir: Dir8) -> Line<'_, T> {
+        let step = dir.offset();
+        let steps_left = |at: i64, step: i64, size: usize| match step {
+            0 => usize::MAX,
+            1 => usize::try_from(size as i64 - at).unwrap_or(0),
+            _ => usize::try_from(at + 1).unwrap_or(0),
+        };
+        let len = if self.contains(start) {
+            steps_left(start.x, step.x, self.width).min(steps_left(start.y, step.y, self.height))
+        } else {
+            0
+        };
+        self.line(start, dir, len)
+    }
+
+    fn line(&self, start: Point2, dir: Dir8, len: usize) -> Line<'_, T> {
+        Line {
+            grid: self,
+            pos: start,
+            step: dir.offset(),
+            len,
+        }
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` is off the grid.
+    fn index(&self, pos: Point2) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is off the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2) -> &mut T {
+        match self.index_of(pos) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!("{:?} is off the grid", pos),
+        }
+    }
+}
+
+/// Prints the grid the way puzzles draw it, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The cells along a straight line of a grid, borrowed from it.
+#[derive(Clone, Debug)]
+pub struct Line<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Point2,
+    step: Point2,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Line<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let cell = &self.grid[self.pos];
+        self.pos += self.step;
+        self.len -= 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Line<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(&self.grid[self.pos + self.step * self.len as i64])
+    }
+}
+
+impl<T> ExactSizeIterator for Line<'_, T> {}
+
+/// Parses lines of cells into a grid, reading each character with `cell`.
+///
+/// Stops at the first character `cell` rejects, so a trailing newline or
+/// blank line is left for the caller. Fails if the rows differ in length.
+pub fn parse_grid<'a, T>(
+    cell: impl Fn(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input| {
+        let (rest, rows) =
+            separated_list1(line_ending, many1(map_opt(none_of("\r\n"), &cell))).parse(input)?;
+        let width = rows[0].len();
+        if let Some(bad) = rows.iter().position(|row| row.len() != width) {
+            let line = input.lines().nth(bad).unwrap_or(input);
+            return Err(nom::Err::Failure(Error::new(line, ErrorKind::Verify)));
+        }
+        Ok((rest, Grid::new(width, rows.into_iter().flatten().collect())))
+    }
+}
+
+/// Parses lines of any non-newline characters into a grid of `char`s.
+pub fn char_grid(input: &str) -> IResult<&str, Grid<char>> {
+    parse_grid(Some)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        let (_, grid) = parse_grid(|c| c.to_digit(10))(input).expect("digits should parse");
+        grid
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = Line<'a, u32>>) -> Vec<Vec<u32>> {
+        lines.map(|line| line.copied().collect()).collect()
+    }
+
+    #[test]
+    fn test_get_is_none_off_the_grid() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Point2::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, -1)), None);
+        assert_eq!(grid[Point2::new(1, 0)], 2);
+    }
+
+    #[test]
+    fn test_neighbors_stay_on_the_grid() {
+        let grid = digits("123\n456\n789");
+
+        let corner = grid
+            .neighbors8(Point2::new(0, 0))
+            .map(|(_, &c)| c)
+            .collect::<Vec<_>>();
+        let middle = grid
+            .neighbors4(Point2::new(1, 1))
+            .map(|(_, &c)| c)
+            .collect::<Vec<_>>();
+
+        assert_eq!(corner, vec![2, 5, 4]);
+        assert_eq!(middle, vec![2, 6, 8, 4]);
+    }
+
+    #[test]
+    fn test_line_views_on_a_non_square_grid() {
+        let grid = digits("123\n456");
+
+        assert_eq!(
+            collect(grid.columns()),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            collect(grid.diagonals()),
+            vec![vec![1, 5], vec![2, 6], vec![3], vec![4]]
+        );
+        assert_eq!(
+            collect(grid.anti_diagonals()),
+            vec![vec![1], vec![2, 4], vec![3, 5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn test_lines_cover_every_direction() {
+        let grid = digits("123\n456\n789\n000");
+
+        assert_eq!(
+            collect(grid.lines(Dir8::Left)),
+            vec![vec![3, 2, 1], vec![6, 5, 4], vec![9, 8, 7], vec![0, 0, 0]]
+        );
+        assert_eq!(
+            collect(grid.lines(Dir8::Up)),
+            vec![vec![0, 7, 4, 1], vec![0, 8, 5, 2], vec![0, 9, 6, 3]]
+        );
+        assert_eq!(
+            collect(grid.lines(Dir8::UpLeft)),
+            vec![
+                vec![3],
+                vec![6, 2],
+                vec![9, 5, 1],
+                vec![0],
+                vec![0, 7],
+                vec![0, 8, 4]
+            ]
+        );
+        for dir in Dir8::ALL {
+            let cells = grid.lines(dir).map(|line| line.len()).sum::<usize>();
+            assert_eq!(cells, 12, "lines along {:?}", dir);
+        }
+    }
+
+    #[test]
+    fn test_rays_stop_at_the_edge_and_reverse() {
+        let grid = digits("123\n456\n789");
+
+        assert_eq!(
+            grid.ray((0, 2).into(), Dir8::UpRight)
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![7, 5, 3]
+        );
+        assert_eq!(
+            grid.ray((1, 1).into(), Dir8::Down)
+                .rev()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![8, 5]
+        );
+        assert_eq!(grid.ray((3, 0).into(), Dir8::Left).count(), 0);
+    }
+
+    #[test]
+    fn test_find_and_map() {
+        let grid = digits("120\n301");
+
+        assert_eq!(grid.position(|&c| c == 3), Some(Point2::new(0, 1)));
+        assert_eq!(
+            grid.positions_where(|&c| c == 0).collect::<Vec<_>>(),
+            vec![Point2::new(2, 0), Point2::new(1, 1)]
+        );
+        assert_eq!(grid.map(|&c| c * 2).to_string(), "240\n602\n");
+    }
+
+    #[test]
+    fn test_parse_grid_rejects_ragged_rows() {
+        let input = "12\n345\n";
+
+        let Err(nom::Err::Failure(e)) = parse_grid(|c| c.to_digit(10))(input) else {
+            panic!("ragged rows should fail");
+        };
+
+        assert_eq!(e.input, "345");
+    }
+}
@@ -0,0 +1,40 @@
+/// An integer type that can bound an interval.
+pub trait Endpoint: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next value up, or `None` at [`MAX`](Endpoint::MAX).
+    fn next(self) -> Option<Self>;
+
+    /// The next value down, or `None` at [`MIN`](Endpoint::MIN).
+    fn prev(self) -> Option<Self>;
+
+    /// How many values `start..=end` holds. Wide enough for any range of a
+    /// 64-bit type.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {
+        $(
+            impl Endpoint for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128 + 1) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(i32, i64, isize, u32, u64, usize);
@@ -0,0 +1,5 @@
+mod endpoint;
+mod set;
+
+pub use endpoint::Endpoint;
+pub use set::IntervalSet;
@@ -0,0 +1,190 @@
+use std::ops::RangeInclusive;
+
+use crate::Endpoint;
+
+/// A set of integers kept as sorted, disjoint inclusive ranges.
+///
+/// Overlapping and touching ranges are merged on insert, so `1..=3` and
+/// `4..=6` are stored as `1..=6`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = u64> {
+    /// `(start, end)` pairs, ascending, with a gap of at least one value
+    /// between neighbours.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with any ranges it overlaps or
+    /// touches. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges ending more than one before `start` stay, as do those
+        // starting more than one after `end`; everything between merges.
+        let lo = self
+            .ranges
+            .partition_point(|&(_, e)| e.next().is_some_and(|after| after < start));
+        let hi = self
+            .ranges
+            .partition_point(|&(s, _)| end.next().is_none_or(|after| s <= after));
+        if lo < hi {
+            start = start.min(self.ranges[lo].0);
+            end = end.max(self.ranges[hi - 1].1);
+        }
+        self.ranges.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(idx).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// How many values the set holds.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(s, e)| T::count(s, e)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (s, e) = (s1.max(s2), e1.min(e2));
+            if s <= e {
+                ranges.push((s, e));
+            }
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.extend(other.iter());
+        set
+    }
+
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    /// Values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (lo, hi) = bounds.into_inner();
+        let mut ranges = Vec::new();
+        let mut from = Some(lo);
+        for &(s, e) in &self.ranges {
+            let Some(gap_start) = from.filter(|&f| f <= hi) else {
+                break;
+            };
+            if e < gap_start {
+                continue;
+            }
+            if let Some(gap_end) = s.prev().filter(|&p| p >= gap_start) {
+                ranges.push((gap_start, gap_end.min(hi)));
+            }
+            from = e.next();
+        }
+        if let Some(gap_start) = from.filter(|&f| f <= hi) {
+            ranges.push((gap_start, hi));
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<u64>) -> Vec<RangeInclusive<u64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_touching() {
+        let set = IntervalSet::from_iter([
+            10..=14,
+            3..=5,
+            16..=20,
+            12..=18,
+            6..=6,
+            RangeInclusive::new(30, 29),
+        ]);
+
+        assert_eq!(ranges(&set), vec![3..=6, 10..=20]);
+        assert_eq!(set.len(), 15);
+        assert!(set.contains(3) && set.contains(6) && set.contains(17));
+        assert!(!set.contains(2) && !set.contains(7) && !set.contains(21));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([1..=10, 20..=30]);
+        let b = IntervalSet::from_iter([5..=25]);
+
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25]);
+        assert_eq!(ranges(&a.union(&b)), vec![1..=30]);
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19]);
+        assert_eq!(ranges(&a.complement(0..=35)), vec![0..=0, 11..=19, 31..=35]);
+        assert_eq!(ranges(&a.complement(3..=22)), vec![11..=19]);
+    }
+
+    #[test]
+    fn test_extremes_do_not_overflow() {
+        let mut set = IntervalSet::from_iter([0..=u64::MAX - 1]);
+        set.insert(u64::MAX..=u64::MAX);
+
+        assert_eq!(ranges(&set), vec![0..=u64::MAX]);
+        assert_eq!(set.len(), u64::MAX as u128 + 1);
+        assert!(set.complement(0..=u64::MAX).is_empty());
+        assert_eq!(
+            IntervalSet::from_iter([i64::MIN..=-1]).complement(i64::MIN..=i64::MAX),
+            IntervalSet::from_iter([0..=i64::MAX])
+        );
+    }
+}
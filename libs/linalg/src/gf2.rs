@@ -0,0 +1,261 @@
+use std::fmt;
+use std::ops::BitXorAssign;
+
+/// A vector over GF(2), packed 64 entries to a word.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    /// `len` zeros.
+    pub fn zeros(len: usize) -> Self {
+        BitVector {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} of {}", i, self.len);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {} of {}", i, self.len);
+        let mask = 1 << (i % 64);
+        if value {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    /// How many entries are one.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Every entry in order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// The indices of the entries that are one, ascending.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter()
+            .enumerate()
+            .filter(|&(_, bit)| bit)
+            .map(|(i, _)| i)
+    }
+}
+
+impl BitXorAssign<&BitVector> for BitVector {
+    fn bitxor_assign(&mut self, other: &BitVector) {
+        assert_eq!(self.len, other.len, "xor of different lengths");
+        for (w, o) in self.words.iter_mut().zip(&other.words) {
+            *w ^= o;
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVector {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut vector = BitVector::zeros(0);
+        for bit in iter {
+            if vector.len.is_multiple_of(64) {
+                vector.words.push(0);
+            }
+            vector.len += 1;
+            vector.set(vector.len - 1, bit);
+        }
+        vector
+    }
+}
+
+/// Why the solutions of a [`Gf2Solution`] could not be listed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gf2Error {
+    /// The null space has this many dimensions, 64 or more, which is too
+    /// many solutions to count.
+    TooManySolutions(usize),
+}
+
+impl fmt::Display for Gf2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Gf2Error::TooManySolutions(free) => {
+                write!(
+                    f,
+                    "{} free variables are too many to try every solution",
+                    free
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Gf2Error {}
+
+impl From<Gf2Error> for aoc_core::Error {
+    fn from(error: Gf2Error) -> Self {
+        aoc_core::Error::invariant(error.to_string())
+    }
+}
+
+/// Every solution of a system over GF(2): one particular solution plus any
+/// combination of the null space basis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2Solution {
+    pub particular: BitVector,
+    pub null_space: Vec<BitVector>,
+}
+
+/// Solves `a x = b` over GF(2) by Gaussian elimination, where `a` is a list
+/// of rows as long as `x`. `None` if the system is inconsistent.
+pub fn solve_gf2(a: &[BitVector], b: &BitVector) -> Option<Gf2Solution> {
+    assert_eq!(a.len(), b.len(), "one target per row");
+    let width = a.first().map_or(0, BitVector::len);
+    let mut rows: Vec<(BitVector, bool)> = a.iter().cloned().zip(b.iter()).collect();
+
+    let mut pivots = Vec::new();
+    for col in 0..width {
+        let top = pivots.len();
+        let Some(found) = (top..rows.len()).find(|&r| rows[r].0.get(col)) else {
+            continue;
+        };
+        rows.swap(top, found);
+        let (pivot_row, pivot_target) = rows[top].clone();
+        for (r, (row, target)) in rows.iter_mut().enumerate() {
+            if r != top && row.get(col) {
+                *row ^= &pivot_row;
+                *target ^= pivot_target;
+            }
+        }
+        pivots.push(col);
+    }
+    if rows[pivots.len()..].iter().any(|&(_, target)| target) {
+        return None;
+    }
+
+    let mut particular = BitVector::zeros(width);
+    for (&col, (_, target)) in pivots.iter().zip(&rows) {
+        particular.set(col, *target);
+    }
+    let null_space = (0..width)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut v = BitVector::zeros(width);
+            v.set(free, true);
+            for (&col, (row, _)) in pivots.iter().zip(&rows) {
+                v.set(col, row.get(free));
+            }
+            v
+        })
+        .collect();
+    Some(Gf2Solution {
+        particular,
+        null_space,
+    })
+}
+
+impl Gf2Solution {
+    /// Every solution, each once. There are `2^null_space.len()` of them,
+    /// visited in Gray code order so each step is a single xor. An error if
+    /// the null space has 64 or more dimensions, as there are too many to
+    /// count.
+    pub fn solutions(&self) -> Result<impl Iterator<Item = BitVector> + '_, Gf2Error> {
+        let count = 1u64
+            .checked_shl(self.null_space.len() as u32)
+            .ok_or(Gf2Error::TooManySolutions(self.null_space.len()))?;
+        let mut current = self.particular.clone();
+        Ok((0..count).map(move |step| {
+            if step > 0 {
+                current ^= &self.null_space[step.trailing_zeros() as usize];
+            }
+            current.clone()
+        }))
+    }
+
+    /// A solution with the fewest ones, by trying them all. The first found
+    /// wins ties.
+    pub fn min_weight(&self) -> Result<BitVector, Gf2Error> {
+        Ok(self
+            .solutions()?
+            .min_by_key(BitVector::count_ones)
+            .expect("there is always a particular solution"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitVector {
+        s.chars().map(|c| c == '1').collect()
+    }
+
+    #[test]
+    fn test_bit_vector() {
+        let mut v = BitVector::zeros(130);
+        v.set(0, true);
+        v.set(129, true);
+        v ^= &BitVector::from_iter((0..130).map(|i| i == 0 || i == 64));
+
+        assert_eq!(v.ones().collect::<Vec<_>>(), vec![64, 129]);
+        assert_eq!(v.count_ones(), 2);
+        assert!(!v.is_zero());
+    }
+
+    #[test]
+    fn test_solve_gf2() {
+        // Lights .##. with buttons (3) (1,3) (2) (2,3) (0,2) (0,1).
+        let a = [
+            bits("000011"),
+            bits("010001"),
+            bits("001110"),
+            bits("110100"),
+        ];
+        let b = bits("0110");
+
+        let solution = solve_gf2(&a, &b).unwrap();
+
+        assert_eq!(solution.null_space.len(), 2);
+        let all: Vec<BitVector> = solution.solutions().unwrap().collect();
+        assert_eq!(all.len(), 4);
+        for x in &all {
+            for (row, target) in a.iter().zip(b.iter()) {
+                let ones = row.iter().zip(x.iter()).filter(|&(r, x)| r && x).count();
+                assert_eq!(ones % 2 == 1, target);
+            }
+        }
+        assert_eq!(solution.min_weight().unwrap().count_ones(), 2);
+    }
+
+    #[test]
+    fn test_too_many_free_variables_to_enumerate() {
+        let solution = solve_gf2(&[BitVector::zeros(64)], &bits("0")).unwrap();
+
+        assert_eq!(solution.null_space.len(), 64);
+        assert!(solution.solutions().is_err());
+        assert_eq!(solution.min_weight(), Err(Gf2Error::TooManySolutions(64)));
+    }
+
+    #[test]
+    fn test_inconsistent_system() {
+        assert_eq!(solve_gf2(&[bits("11"), bits("11")], &bits("10")), None);
+    }
+}
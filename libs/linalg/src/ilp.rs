@@ -0,0 +1,328 @@
+use std::fmt;
+
+use crate::rational::{Rational, lcm};
+
+/// Why [`minimize_sum`] found no answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IlpError {
+    /// No non-negative integer `x` satisfies the equations.
+    Infeasible,
+    /// Variable `x[i]` is left free by the equations and no row with
+    /// non-negative coefficients caps it, so it cannot be searched.
+    Unbounded(usize),
+    /// Eliminating the equations took a coefficient past what an `i64` holds.
+    Overflow,
+}
+
+impl fmt::Display for IlpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IlpError::Infeasible => write!(f, "no non-negative integer solution"),
+            IlpError::Unbounded(i) => write!(f, "variable x{} has no upper bound", i),
+            IlpError::Overflow => write!(f, "a coefficient does not fit in an i64"),
+        }
+    }
+}
+
+impl std::error::Error for IlpError {}
+
+impl From<IlpError> for aoc_core::Error {
+    fn from(error: IlpError) -> Self {
+        match error {
+            IlpError::Infeasible => aoc_core::Error::no_solution(error.to_string()),
+            IlpError::Unbounded(_) | IlpError::Overflow => {
+                aoc_core::Error::invariant(error.to_string())
+            }
+        }
+    }
+}
+
+/// The non-negative integer `x` with `a x = b` whose entries have the
+/// smallest sum. `a` is a list of rows, each as long as `x`.
+///
+/// The equations are put in reduced row echelon form over the rationals,
+/// which writes each pivot variable in terms of the free ones. The free
+/// variables are then searched exhaustively, so this is meant for small
+/// systems with few free variables. Each free variable needs an upper
+/// bound, which comes from any row whose coefficients are all non-negative.
+pub fn minimize_sum(a: &[Vec<i64>], b: &[i64]) -> Result<Vec<u64>, IlpError> {
+    assert_eq!(a.len(), b.len(), "one target per row");
+    let width = a.first().map_or(0, Vec::len);
+    let bounds = upper_bounds(a, b, width)?;
+
+    let mut rows: Vec<Vec<Rational>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &target)| row.iter().chain([&target]).map(|&v| v.into()).collect())
+        .collect();
+    let pivots = reduce(&mut rows, width);
+    if rows[pivots.len()..].iter().any(|row| !row[width].is_zero()) {
+        return Err(IlpError::Infeasible);
+    }
+
+    let free: Vec<usize> = (0..width).filter(|col| !pivots.contains(col)).collect();
+    let free_bounds = free
+        .iter()
+        .map(|&col| bounds[col].ok_or(IlpError::Unbounded(col)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut search = Search::new(&rows[..pivots.len()], &free, free_bounds, width)?;
+    let mut rest = search.targets.clone();
+    let mut values = vec![0; free.len()];
+    search.run(0, &mut rest, search.base_cost, &mut values);
+    let (_, free_values) = search.best.ok_or(IlpError::Infeasible)?;
+
+    let mut x = vec![0; width];
+    for (&col, &value) in free.iter().zip(&free_values) {
+        x[col] = value as u64;
+    }
+    let mut rest = search.targets.clone();
+    for (i, &value) in free_values.iter().enumerate() {
+        for (r, row) in rest.iter_mut().zip(&search.coeffs) {
+            *r -= row[i] * value;
+        }
+    }
+    for ((&col, r), scale) in pivots.iter().zip(rest).zip(&search.scales) {
+        x[col] = (r / scale) as u64;
+    }
+    Ok(x)
+}
+
+/// The most each variable can be, from rows whose coefficients are all
+/// non-negative. A column of zeros is capped at zero, since raising it only
+/// adds to the sum.
+fn upper_bounds(a: &[Vec<i64>], b: &[i64], width: usize) -> Result<Vec<Option<i64>>, IlpError> {
+    let mut bounds: Vec<Option<i64>> = (0..width)
+        .map(|col| a.iter().all(|row| row[col] == 0).then_some(0))
+        .collect();
+    for (row, &target) in a.iter().zip(b) {
+        if row.iter().any(|&v| v < 0) {
+            continue;
+        }
+        if target < 0 {
+            return Err(IlpError::Infeasible);
+        }
+        for (bound, &v) in bounds.iter_mut().zip(row) {
+            if v > 0 {
+                let cap = target / v;
+                *bound = Some(bound.map_or(cap, |b| b.min(cap)));
+            }
+        }
+    }
+    Ok(bounds)
+}
+
+/// Gauss-Jordan elimination of the augmented `rows` in place, moving the
+/// pivot rows to the top with their pivots scaled to one. Returns the pivot
+/// column of each of those rows.
+fn reduce(rows: &mut [Vec<Rational>], width: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for col in 0..width {
+        let top = pivots.len();
+        let Some(found) = (top..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(top, found);
+        let pivot = rows[top][col];
+        for v in rows[top].iter_mut() {
+            *v = *v / pivot;
+        }
+        let pivot_row = rows[top].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if r == top || factor.is_zero() {
+                continue;
+            }
+            for (v, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *v = *v - factor * p;
+            }
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+/// Branch and bound over the free variables.
+///
+/// Pivot row `p` is scaled to integers as
+/// `scales[p] * pivot + sum(coeffs[p][i] * free[i]) = targets[p]`. Costs are
+/// kept multiplied by the lcm of the scales so they are integers too.
+struct Search {
+    bounds: Vec<i64>,
+    coeffs: Vec<Vec<i64>>,
+    scales: Vec<i64>,
+    targets: Vec<i64>,
+    /// The scaled cost of raising each free variable by one.
+    weights: Vec<i64>,
+    /// The scaled cost with every free variable at zero.
+    base_cost: i64,
+    /// `headroom[i][p]`: the most the free variables from `i` on can add to
+    /// what is left for pivot `p`.
+    headroom: Vec<Vec<i64>>,
+    /// `savings[i]`: the most the free variables from `i` on can lower the
+    /// scaled cost.
+    savings: Vec<i64>,
+    best: Option<(i64, Vec<i64>)>,
+}
+
+impl Search {
+    fn new(
+        rows: &[Vec<Rational>],
+        free: &[usize],
+        bounds: Vec<i64>,
+        width: usize,
+    ) -> Result<Self, IlpError> {
+        let int = |v: i128| i64::try_from(v).map_err(|_| IlpError::Overflow);
+        let mut coeffs = Vec::with_capacity(rows.len());
+        let mut scales = Vec::with_capacity(rows.len());
+        let mut targets = Vec::with_capacity(rows.len());
+        for row in rows {
+            let scale = free
+                .iter()
+                .chain([&width])
+                .fold(1, |acc, &col| lcm(acc, row[col].den()));
+            let scaled = |v: Rational| int(v.num() * (scale / v.den()));
+            coeffs.push(
+                free.iter()
+                    .map(|&col| scaled(row[col]))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            scales.push(int(scale)?);
+            targets.push(scaled(row[width])?);
+        }
+
+        let unit = scales
+            .iter()
+            .try_fold(1, |acc: i64, &s| int(lcm(acc.into(), s.into())))?;
+        let base_cost = targets
+            .iter()
+            .zip(&scales)
+            .map(|(t, s)| t * (unit / s))
+            .sum();
+        let weights: Vec<i64> = (0..free.len())
+            .map(|i| {
+                unit - coeffs
+                    .iter()
+                    .zip(&scales)
+                    .map(|(row, s)| row[i] * (unit / s))
+                    .sum::<i64>()
+            })
+            .collect();
+
+        let mut headroom = vec![vec![0; rows.len()]; free.len() + 1];
+        let mut savings = vec![0; free.len() + 1];
+        for i in (0..free.len()).rev() {
+            for p in 0..rows.len() {
+                headroom[i][p] = headroom[i + 1][p] + (-coeffs[p][i]).max(0) * bounds[i];
+            }
+            savings[i] = savings[i + 1] + (-weights[i]).max(0) * bounds[i];
+        }
+
+        Ok(Search {
+            bounds,
+            coeffs,
+            scales,
+            targets,
+            weights,
+            base_cost,
+            headroom,
+            savings,
+            best: None,
+        })
+    }
+
+    fn run(&mut self, i: usize, rest: &mut [i64], cost: i64, values: &mut [i64]) {
+        if rest.iter().zip(&self.headroom[i]).any(|(r, h)| r + h < 0) {
+            return;
+        }
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|(best, _)| cost - self.savings[i] >= *best)
+        {
+            return;
+        }
+        if i == values.len() {
+            if rest.iter().zip(&self.scales).all(|(r, s)| r % s == 0) {
+                self.best = Some((cost, values.to_vec()));
+            }
+            return;
+        }
+
+        for value in 0..=self.bounds[i] {
+            values[i] = value;
+            self.run(i + 1, rest, cost + self.weights[i] * value, values);
+            for (r, row) in rest.iter_mut().zip(&self.coeffs) {
+                *r -= row[i];
+            }
+        }
+        for (r, row) in rest.iter_mut().zip(&self.coeffs) {
+            *r += row[i] * (self.bounds[i] + 1);
+        }
+        values[i] = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize_sum() {
+        // Buttons (3) (1,3) (2) (2,3) (0,2) (0,1) against counters {3,5,4,7}.
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let b = [3, 5, 4, 7];
+
+        let x = minimize_sum(&a, &b).unwrap();
+
+        assert_eq!(x.iter().sum::<u64>(), 10);
+        for (row, &target) in a.iter().zip(&b) {
+            let total: i64 = row.iter().zip(&x).map(|(&c, &v)| c * v as i64).sum();
+            assert_eq!(total, target);
+        }
+    }
+
+    #[test]
+    fn test_fractional_pivots_need_integer_solutions() {
+        // x + 2y = 4 has the rational solution x = 0, y = 2 as its cheapest,
+        // while 2x + 2y + 3z = 7 forces z to be odd.
+        assert_eq!(minimize_sum(&[vec![1, 2]], &[4]), Ok(vec![0, 2]));
+        assert_eq!(minimize_sum(&[vec![2, 2, 3]], &[7]), Ok(vec![2, 0, 1]));
+        assert_eq!(minimize_sum(&[vec![2, 4]], &[3]), Err(IlpError::Infeasible));
+    }
+
+    #[test]
+    fn test_unsolvable_systems() {
+        assert_eq!(
+            minimize_sum(&[vec![1, 1], vec![1, 1]], &[2, 3]),
+            Err(IlpError::Infeasible)
+        );
+        assert_eq!(
+            minimize_sum(&[vec![1, -1]], &[-2]),
+            Err(IlpError::Unbounded(1))
+        );
+        assert_eq!(
+            minimize_sum(&[vec![1, -1, 0], vec![0, 1, -1]], &[0, 0]),
+            Err(IlpError::Unbounded(2))
+        );
+        assert_eq!(
+            minimize_sum(&[vec![1, 0]], &[-1]),
+            Err(IlpError::Infeasible)
+        );
+    }
+
+    #[test]
+    fn test_scales_past_i64_are_an_error() {
+        // Pivots of 2^40 and 2^40 + 1 have a common multiple past i64::MAX.
+        let (p, q) = (1 << 40, (1 << 40) + 1);
+        assert_eq!(
+            minimize_sum(&[vec![p, 0, 1], vec![0, q, 1]], &[p, q]),
+            Err(IlpError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod gf2;
+mod ilp;
+mod rational;
+
+pub use gf2::{BitVector, Gf2Error, Gf2Solution, solve_gf2};
+pub use ilp::{IlpError, minimize_sum};
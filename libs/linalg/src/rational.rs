@@ -0,0 +1,109 @@
+use std::ops::{Div, Mul, Neg, Sub};
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Rational {
+    num: i128,
+    den: i128,
+}
+
+pub(crate) fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+pub(crate) fn lcm(a: i128, b: i128) -> i128 {
+    a / gcd(a, b) * b
+}
+
+impl Rational {
+    /// `num / den`. Panics if `den` is zero.
+    pub(crate) fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "zero denominator");
+        let g = gcd(num, den) * den.signum();
+        Rational {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    pub(crate) fn num(self) -> i128 {
+        self.num
+    }
+
+    pub(crate) fn den(self) -> i128 {
+        self.den
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self.num == 0
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational {
+            num: n.into(),
+            den: 1,
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        let den = lcm(self.den, other.den);
+        Rational::new(
+            self.num * (den / self.den) - other.num * (den / other.den),
+            den,
+        )
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_stays_in_lowest_terms() {
+        let half = Rational::new(2, 4);
+        let third = Rational::new(-1, -3);
+
+        assert_eq!((half.num(), half.den()), (1, 2));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(third / -half, Rational::new(-2, 3));
+        assert!((half - half).is_zero());
+        assert_eq!(Rational::new(3, -6).den(), 2);
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, RandomState};
+
+/// A cache of a recursive function's results, keyed by its arguments.
+///
+/// The function takes the memo as its first argument and computes each
+/// value through [`get_or_compute`](Memo::get_or_compute), which hands the
+/// memo back for the recursive calls:
+///
+/// ```
+/// use aoc_memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| {
+///         if n < 2 { n } else { fib(memo, n - 1) + fib(memo, n - 2) }
+///     })
+/// }
+///
+/// assert_eq!(fib(&mut Memo::new(), 90), 2880067194370816120);
+/// ```
+///
+/// `S` picks the hasher, for keys where the default one is the bottleneck.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V, S = RandomState> {
+    table: HashMap<K, V, S>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How well a [`Memo`] has been doing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries in the table.
+    pub size: usize,
+}
+
+impl Stats {
+    /// The share of lookups answered from the table, from 0 to 1.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} entries, {} hits, {} misses ({:.1}% hit rate)",
+            self.size,
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Memo {
+            table: HashMap::with_hasher(hasher),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.table.len(),
+        }
+    }
+
+    /// Forgets every entry and resets the stats.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+impl<K: Eq + Hash, V: Clone, S: BuildHasher> Memo<K, V, S> {
+    /// The cached value for `key`, or the result of `compute`, which is
+    /// cached before it is returned.
+    ///
+    /// `compute` gets the memo back so it can recurse through it. A key
+    /// that depends on itself recurses forever, as the plain function
+    /// would.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.table.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self, &key);
+        self.table.insert(key, value.clone());
+        value
+    }
+
+    /// The cached value for `key`, without computing or counting it.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.table.get(key)
+    }
+
+    /// Seeds the table with a known value.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.table.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::{BuildHasherDefault, DefaultHasher};
+
+    fn paths(memo: &mut Memo<(u32, u32), u64>, x: u32, y: u32) -> u64 {
+        memo.get_or_compute((x, y), |memo, &(x, y)| {
+            if x == 0 || y == 0 {
+                1
+            } else {
+                paths(memo, x - 1, y) + paths(memo, x, y - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn test_results_are_cached() {
+        let mut memo = Memo::new();
+
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+        let stats = memo.stats();
+        assert_eq!(stats.size, 17 * 17 - 1);
+        assert_eq!(stats.misses, stats.size as u64);
+        assert_eq!(stats.hits, 2 * 16 * 16 - stats.misses + 1);
+
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+        assert_eq!(memo.stats().misses, stats.misses);
+        assert_eq!(memo.stats().hits, stats.hits + 1);
+    }
+
+    #[test]
+    fn test_seeded_values_and_custom_hasher() {
+        let mut memo: Memo<u32, u32, BuildHasherDefault<DefaultHasher>> =
+            Memo::with_hasher(Default::default());
+        memo.insert(1, 10);
+
+        assert_eq!(memo.get_or_compute(1, |_, _| unreachable!()), 10);
+        assert_eq!(memo.get_or_compute(2, |_, &k| k * 2), 4);
+        assert_eq!(memo.get(&2), Some(&4));
+        assert_eq!(
+            memo.stats().to_string(),
+            "2 entries, 1 hits, 1 misses (50.0% hit rate)"
+        );
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+    }
+}